@@ -1,10 +1,14 @@
-use crate::axis::plot::Plot2D;
+use crate::axis::plot::{color::Color, Plot2D};
+use crate::dimension::Dimension;
 use std::fmt;
 
 // Only imported for documentation. If you notice that this is no longer the
 // case, please change it.
 #[allow(unused_imports)]
-use crate::Picture;
+use crate::{
+    axis::plot::{coordinate::SymbolicCoordinate2D, Type2D},
+    Picture,
+};
 
 /// Plot inside an [`Axis`] environment.
 pub mod plot;
@@ -30,6 +34,218 @@ pub enum AxisKey {
     XLabel(String),
     /// Control the label of the *y* axis.
     YLabel(String),
+    /// Add ticks at the given positions of the *x* axis. These are drawn in
+    /// addition to the default ticks instead of replacing them.
+    ExtraXTicks(Vec<f64>),
+    /// Add ticks at the given positions of the *y* axis. These are drawn in
+    /// addition to the default ticks instead of replacing them.
+    ExtraYTicks(Vec<f64>),
+    /// Control the style of the ticks added with [`AxisKey::ExtraXTicks`] e.g.
+    /// draw them in a different color or with a grid line.
+    ExtraXTickStyle(Vec<String>),
+    /// Control the style of the tick labels of the *y* axis. For example,
+    /// `vec!["text width=1cm", "align=right"]` right-aligns the numbers of all
+    /// tick labels (see [`Axis::set_y_tick_labels_right_aligned`]).
+    YTickLabelStyle(Vec<String>),
+    /// Control the direction of the *x* axis.
+    XDir(AxisDir),
+    /// Control the direction of the *y* axis.
+    YDir(AxisDir),
+    /// Control the number of columns of the legend. A value of `-1` places
+    /// all the legend entries in a single row.
+    LegendColumns(i32),
+    /// Control the style of the title of the axis environment.
+    TitleStyle(Vec<String>),
+    /// Define a new colormap and use it for the axis environment e.g. to color
+    /// scatter points or surfaces.
+    DefineColormap(Colormap),
+    /// Control the viewing angle of three-dimensional plots. The first value
+    /// is the azimuth and the second value is the elevation (both in degrees).
+    /// This has no effect on two-dimensional plots.
+    View(f64, f64),
+    /// Control the lower limit of the *x* axis.
+    XMin(f64),
+    /// Control the upper limit of the *x* axis.
+    XMax(f64),
+    /// Control the lower limit of the *y* axis.
+    YMin(f64),
+    /// Control the upper limit of the *y* axis.
+    YMax(f64),
+    /// Control the width of the axis. By default this includes the tick labels
+    /// and axis labels, unless [`AxisKey::ScaleOnlyAxis`] is set.
+    Width(Dimension),
+    /// Control the height of the axis. By default this includes the tick
+    /// labels and axis labels, unless [`AxisKey::ScaleOnlyAxis`] is set.
+    Height(Dimension),
+    /// Control whether [`AxisKey::Width`] and [`AxisKey::Height`] apply only
+    /// to the axis itself (ignoring the tick labels and axis labels).
+    ScaleOnlyAxis(bool),
+    /// Control whether markers are clipped at the limits of the axis together
+    /// with the rest of the plot. By default they are not, so markers of
+    /// coordinates on the edges are drawn complete.
+    ClipMarkerPaths(bool),
+    /// Control the width of the bars of all the bar plots in the axis (see
+    /// e.g. [`Type2D::YBar`]).
+    BarWidth(Dimension),
+    /// Control the shift of the bars of all the bar plots in the axis (see
+    /// e.g. [`Type2D::YBar`]).
+    BarShift(Dimension),
+    /// Categories of a symbolic *x* axis. The *x* values of the coordinates
+    /// then have to be one of these categories (see
+    /// [`SymbolicCoordinate2D`]).
+    SymbolicXCoords(Vec<String>),
+    /// Control the style of the grid lines e.g. `vec!["dashed", "gray!30"]`.
+    /// Note that grid lines are only drawn if they are enabled (see
+    /// [`AxisKey::Grid`]).
+    GridStyle(Vec<String>),
+    /// Control whether the limits of the axis are enlarged beyond the range
+    /// of the data. By default they are.
+    EnlargeLimits(bool),
+    /// Control whether the plots are clipped at the limits of the axis. By
+    /// default they are.
+    Clip(bool),
+    /// Control whether the axis lines, ticks, and grid are drawn on top of the
+    /// plots. By default they are drawn below e.g. filled areas cover them.
+    AxisOnTop(bool),
+    /// Control the positions of the ticks of the *x* axis. This replaces the
+    /// default ticks (see [`AxisKey::ExtraXTicks`] to add ticks instead).
+    XTick(Ticks),
+    /// Control the text of the labels of the ticks of the *x* axis. The labels
+    /// are assigned to the ticks in order.
+    XTickLabels(Vec<String>),
+    /// Control the style of the labels of the ticks of the *x* axis e.g.
+    /// `vec!["rotate=45", "anchor=east"]`.
+    XTickLabelStyle(Vec<String>),
+    /// Control the style of the images drawn in the legend, applied after the
+    /// style of each plot e.g. `vec!["mark=none"]` to show only lines.
+    LegendImagePostStyle(Vec<String>),
+    /// Enlarge only the upper limit of the *y* axis by the given fraction of
+    /// the range of the data (e.g. to leave room for labels above bars). This
+    /// is finer-grained than [`AxisKey::EnlargeLimits`]. It is rendered as
+    /// `enlarge y limits={value=…,upper}` rather than `{upper=…}`, because
+    /// pgfplots treats `upper` as a flag that does not take a value.
+    ///
+    /// This key, [`AxisKey::EnlargeYLimitsLower`], and
+    /// [`AxisKey::EnlargeYLimitsAbs`] are mutually exclusive with each other
+    /// (i.e. only one limit can be enlarged this way). They are also
+    /// overridden by a later [`AxisKey::EnlargeLimits`] (and vice versa).
+    EnlargeYLimitsUpper(f64),
+    /// Enlarge only the lower limit of the *y* axis by the given fraction of
+    /// the range of the data. This is finer-grained than
+    /// [`AxisKey::EnlargeLimits`]. It is rendered as
+    /// `enlarge y limits={value=…,lower}` rather than `{lower=…}`, because
+    /// pgfplots treats `lower` as a flag that does not take a value. See
+    /// [`AxisKey::EnlargeYLimitsUpper`] for the keys that override each other.
+    EnlargeYLimitsLower(f64),
+    /// Do not place ticks below this value on the *x* axis. This is
+    /// independent of the limits of the axis.
+    XTickMin(f64),
+    /// Do not place ticks above this value on the *x* axis. This is
+    /// independent of the limits of the axis.
+    XTickMax(f64),
+    /// Do not place ticks below this value on the *y* axis. This is
+    /// independent of the limits of the axis.
+    YTickMin(f64),
+    /// Do not place ticks above this value on the *y* axis. This is
+    /// independent of the limits of the axis.
+    YTickMax(f64),
+    /// Treat the coordinates with a *y* value outside of the `(min, max)`
+    /// range as unbounded (see [`AxisKey::UnboundedCoords`]).
+    RestrictYToDomain(f64, f64),
+    /// Control how unbounded coordinates (e.g. infinity or NaN) are handled.
+    UnboundedCoords(UnboundedCoords),
+    /// Control the style of all the tick marks e.g.
+    /// `vec!["line width=0.4pt", "black"]`. This does not affect the tick
+    /// labels.
+    TickStyle(Vec<String>),
+    /// Draw a colorbar at the given position of the axis. This also enables
+    /// the colorbar.
+    ColorbarPosition(ColorbarPos),
+    /// Transform the *x* coordinates with custom TeX code before plotting
+    /// them e.g. `\pgfmathparse{sqrt(#1)}` for a square root scale. The
+    /// `inv_trafo` code has to invert `trafo`; it is used to compute the tick
+    /// labels.
+    XScaleTransform { trafo: String, inv_trafo: String },
+    /// Control the base of the logarithm of the *x* axis when
+    /// `AxisKey::XMode(Scale::Log)` is set.
+    LogBasisX(f64),
+    /// Control the base of the logarithm of the *y* axis when
+    /// `AxisKey::YMode(Scale::Log)` is set.
+    LogBasisY(f64),
+    /// Raw Ti*k*Z code drawn after the axis is finished e.g. `\node`
+    /// commands in `rel axis cs` coordinates.
+    ExtraDescription(String),
+    /// Control the style of the *y* label e.g. `vec!["rotate=-90"]`.
+    YLabelStyle(Vec<String>),
+    /// Control the style of the colorbar e.g. `vec!["width=0.3cm"]`. The
+    /// colorbar is itself an axis, so most axis options can be used.
+    ColorbarStyle(Vec<String>),
+    /// Control the style shared by all the nodes inside the axis (e.g.
+    /// annotations) e.g. `vec!["font=\\small"]`.
+    EveryNodeStyle(Vec<String>),
+    /// Shift the *x* label away from the axis (or towards it if negative).
+    XLabelShift(Dimension),
+    /// Shift the *y* label away from the axis (or towards it if negative).
+    YLabelShift(Dimension),
+    /// Control the default size (radius) of the markers of all the plots in
+    /// the axis.
+    MarkSize(Dimension),
+    /// Control the styles used, in order, by the bar plots of the axis e.g.
+    /// `vec!["fill=blue!30", "fill=red!30"]`. Each element is the style of one
+    /// bar series. This list is used instead of the default cycle list when
+    /// the bars are enabled for the whole axis e.g. with
+    /// `AxisKey::Custom("ybar".into())`.
+    BarCycleList(Vec<String>),
+    /// Control whether the coordinates outside of the limits of the axis (see
+    /// e.g. [`AxisKey::XMin`]) are discarded before plotting. By default they
+    /// are. Unlike [`AxisKey::Clip`], this changes which data is drawn, not
+    /// only what is visible.
+    ClipLimits(bool),
+    /// Draw the legend of the axis somewhere else instead of inside the axis.
+    /// The legend can then be placed anywhere in the document with
+    /// `\ref{name}` (see [`Axis::set_legend_to_name`]).
+    LegendToName(String),
+    /// Control the positions of the ticks of the *y* axis e.g. one tick at
+    /// each bar of a horizontal bar plot with [`Ticks::Data`]. This replaces
+    /// the default ticks (see [`AxisKey::ExtraYTicks`] to add ticks instead).
+    YTick(Ticks),
+    /// Control the horizontal alignment of the text of the entries in the
+    /// legend. Multi-entry legends usually look better aligned to the left.
+    LegendCellAlign(Align),
+    /// Control at which ticks the grid lines are drawn. By default there are
+    /// no grid lines.
+    Grid(Grid),
+    /// Enlarge both limits of the *x* axis by the given amount in data units.
+    /// Unlike a fraction of the range of the data, this gives a precise
+    /// padding. This and [`AxisKey::EnlargeLimits`] both set the enlargement
+    /// of the *x* axis; only the last one has an effect.
+    EnlargeXLimitsAbs(f64),
+    /// Enlarge both limits of the *y* axis by the given amount in data units.
+    /// Unlike a fraction of the range of the data, this gives a precise
+    /// padding. This replaces a previous [`AxisKey::EnlargeYLimitsUpper`] or
+    /// [`AxisKey::EnlargeYLimitsLower`] (and vice versa).
+    EnlargeYLimitsAbs(f64),
+}
+
+impl AxisKey {
+    // Keys that set `enlarge y limits` are mutually exclusive with each other
+    // i.e. pgfplots only applies the last one.
+    fn is_enlarge_y_limits(&self) -> bool {
+        matches!(
+            self,
+            AxisKey::EnlargeYLimitsUpper(_)
+                | AxisKey::EnlargeYLimitsLower(_)
+                | AxisKey::EnlargeYLimitsAbs(_)
+        )
+    }
+    // Colors used by the key. If you add a variant that holds a `Color`,
+    // please add it here too; otherwise it won't be defined in the preamble.
+    pub(crate) fn colors(&self) -> Vec<&Color> {
+        match self {
+            AxisKey::DefineColormap(colormap) => colormap.colors.iter().collect(),
+            _ => Vec::new(),
+        }
+    }
 }
 
 impl fmt::Display for AxisKey {
@@ -41,6 +257,108 @@ impl fmt::Display for AxisKey {
             AxisKey::Title(value) => write!(f, "title={{{value}}}"),
             AxisKey::XLabel(value) => write!(f, "xlabel={{{value}}}"),
             AxisKey::YLabel(value) => write!(f, "ylabel={{{value}}}"),
+            AxisKey::ExtraXTicks(values) => {
+                let values: Vec<String> = values.iter().map(f64::to_string).collect();
+                write!(f, "extra x ticks={{{}}}", values.join(", "))
+            }
+            AxisKey::ExtraYTicks(values) => {
+                let values: Vec<String> = values.iter().map(f64::to_string).collect();
+                write!(f, "extra y ticks={{{}}}", values.join(", "))
+            }
+            AxisKey::ExtraXTickStyle(values) => {
+                write!(f, "extra x tick style={{{}}}", values.join(", "))
+            }
+            AxisKey::YTickLabelStyle(values) => {
+                write!(f, "yticklabel style={{{}}}", values.join(", "))
+            }
+            AxisKey::XDir(value) => write!(f, "x dir={value}"),
+            AxisKey::YDir(value) => write!(f, "y dir={value}"),
+            AxisKey::LegendColumns(value) => write!(f, "legend columns={value}"),
+            AxisKey::TitleStyle(values) => write!(f, "title style={{{}}}", values.join(", ")),
+            AxisKey::DefineColormap(colormap) => {
+                let colors: Vec<String> = colormap
+                    .colors
+                    .iter()
+                    .map(|color| format!("color=({color})"))
+                    .collect();
+                write!(f, "colormap={{{}}}{{{}}}", colormap.name, colors.join(" "))
+            }
+            AxisKey::View(azimuth, elevation) => write!(f, "view={{{azimuth}}}{{{elevation}}}"),
+            AxisKey::XMin(value) => write!(f, "xmin={value}"),
+            AxisKey::XMax(value) => write!(f, "xmax={value}"),
+            AxisKey::YMin(value) => write!(f, "ymin={value}"),
+            AxisKey::YMax(value) => write!(f, "ymax={value}"),
+            AxisKey::Width(value) => write!(f, "width={value}"),
+            AxisKey::Height(value) => write!(f, "height={value}"),
+            AxisKey::ScaleOnlyAxis(value) => write!(f, "scale only axis={value}"),
+            AxisKey::ClipMarkerPaths(value) => write!(f, "clip marker paths={value}"),
+            AxisKey::BarWidth(value) => write!(f, "bar width={value}"),
+            AxisKey::BarShift(value) => write!(f, "bar shift={value}"),
+            AxisKey::SymbolicXCoords(values) => {
+                write!(f, "symbolic x coords={{{}}}", values.join(", "))
+            }
+            AxisKey::GridStyle(values) => write!(f, "grid style={{{}}}", values.join(", ")),
+            AxisKey::EnlargeLimits(value) => write!(f, "enlargelimits={value}"),
+            AxisKey::Clip(value) => write!(f, "clip={value}"),
+            AxisKey::AxisOnTop(value) => write!(f, "axis on top={value}"),
+            AxisKey::XTick(value) => write!(f, "xtick={value}"),
+            AxisKey::XTickLabels(values) => write!(f, "xticklabels={{{}}}", values.join(", ")),
+            AxisKey::XTickLabelStyle(values) => {
+                write!(f, "xticklabel style={{{}}}", values.join(", "))
+            }
+            AxisKey::LegendImagePostStyle(values) => {
+                write!(f, "legend image post style={{{}}}", values.join(", "))
+            }
+            AxisKey::EnlargeYLimitsUpper(value) => {
+                write!(f, "enlarge y limits={{value={value},upper}}")
+            }
+            AxisKey::EnlargeYLimitsLower(value) => {
+                write!(f, "enlarge y limits={{value={value},lower}}")
+            }
+            AxisKey::XTickMin(value) => write!(f, "xtick min={value}"),
+            AxisKey::XTickMax(value) => write!(f, "xtick max={value}"),
+            AxisKey::YTickMin(value) => write!(f, "ytick min={value}"),
+            AxisKey::YTickMax(value) => write!(f, "ytick max={value}"),
+            AxisKey::RestrictYToDomain(min, max) => {
+                write!(f, "restrict y to domain={min}:{max}")
+            }
+            AxisKey::UnboundedCoords(value) => write!(f, "unbounded coords={value}"),
+            AxisKey::TickStyle(values) => write!(f, "tick style={{{}}}", values.join(", ")),
+            AxisKey::ColorbarPosition(value) => write!(f, "{value}"),
+            AxisKey::XScaleTransform { trafo, inv_trafo } => write!(
+                f,
+                "x coord trafo/.code={{{trafo}}}, x coord inv trafo/.code={{{inv_trafo}}}"
+            ),
+            AxisKey::LogBasisX(value) => write!(f, "log basis x={value}"),
+            AxisKey::LogBasisY(value) => write!(f, "log basis y={value}"),
+            AxisKey::ExtraDescription(value) => write!(f, "extra description/.code={{{value}}}"),
+            AxisKey::YLabelStyle(values) => write!(f, "ylabel style={{{}}}", values.join(", ")),
+            AxisKey::ColorbarStyle(values) => {
+                write!(f, "colorbar style={{{}}}", values.join(", "))
+            }
+            AxisKey::EveryNodeStyle(values) => {
+                write!(f, "every node/.style={{{}}}", values.join(", "))
+            }
+            AxisKey::XLabelShift(value) => write!(f, "xlabel shift={value}"),
+            AxisKey::YLabelShift(value) => write!(f, "ylabel shift={value}"),
+            AxisKey::MarkSize(value) => write!(f, "mark size={value}"),
+            AxisKey::BarCycleList(styles) => {
+                write!(f, "bar cycle list/.style={{/pgfplots/cycle list={{")?;
+                for (i, style) in styles.iter().enumerate() {
+                    if i != 0 {
+                        write!(f, ",")?;
+                    }
+                    write!(f, "{{{style}}}")?;
+                }
+                write!(f, "}}}}")
+            }
+            AxisKey::ClipLimits(value) => write!(f, "clip limits={value}"),
+            AxisKey::LegendToName(name) => write!(f, "legend to name={name}"),
+            AxisKey::YTick(value) => write!(f, "ytick={value}"),
+            AxisKey::LegendCellAlign(value) => write!(f, "legend cell align={value}"),
+            AxisKey::Grid(value) => write!(f, "grid={value}"),
+            AxisKey::EnlargeXLimitsAbs(value) => write!(f, "enlarge x limits={{abs={value}}}"),
+            AxisKey::EnlargeYLimitsAbs(value) => write!(f, "enlarge y limits={{abs={value}}}"),
         }
     }
 }
@@ -52,6 +370,7 @@ impl fmt::Display for AxisKey {
 /// ```text
 /// \begin{axis}[AxisKeys]
 ///     % plots
+///     % annotations
 /// \end{axis}
 /// ```
 ///
@@ -75,6 +394,8 @@ impl fmt::Display for AxisKey {
 pub struct Axis {
     keys: Vec<AxisKey>,
     pub plots: Vec<Plot2D>,
+    // Raw TikZ code written after the plots e.g. text annotations.
+    annotations: Vec<String>,
 }
 
 impl fmt::Display for Axis {
@@ -94,6 +415,9 @@ impl fmt::Display for Axis {
         for plot in self.plots.iter() {
             writeln!(f, "{plot}")?;
         }
+        for annotation in self.annotations.iter() {
+            writeln!(f, "\t{annotation}")?;
+        }
 
         write!(f, "\\end{{axis}}")?;
 
@@ -106,6 +430,7 @@ impl From<Plot2D> for Axis {
         Axis {
             keys: Vec::new(),
             plots: vec![plot],
+            annotations: Vec::new(),
         }
     }
 }
@@ -122,6 +447,41 @@ impl Axis {
     pub fn new() -> Self {
         Default::default()
     }
+    /// Creates a new, empty axis environment with space for at least
+    /// `capacity` plots without reallocating.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::axis::Axis;
+    ///
+    /// let axis = Axis::with_capacity(10);
+    /// assert!(axis.plots.capacity() >= 10);
+    /// ```
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            plots: Vec::with_capacity(capacity),
+            ..Default::default()
+        }
+    }
+    /// Append a plot after all the previously added plots, and return a
+    /// mutable reference to it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::axis::{plot::Plot2D, Axis};
+    ///
+    /// let mut axis = Axis::new();
+    /// let plot = axis.push_plot(Plot2D::new());
+    /// plot.coordinates.push((0.0, 0.0).into());
+    /// ```
+    pub fn push_plot(&mut self, plot: Plot2D) -> &mut Plot2D {
+        self.plots.push(plot);
+        self.plots
+            .last_mut()
+            .expect("the pushed plot is the last one")
+    }
     /// Set the title of the axis environment. This can be valid LaTeX e.g.
     /// inline math.
     ///
@@ -136,6 +496,42 @@ impl Axis {
     pub fn set_title<S: Into<String>>(&mut self, title: S) {
         self.add_key(AxisKey::Title(title.into()));
     }
+    /// Set the title of the axis environment and place it below the axis
+    /// (under the tick labels and the *x* label) instead of above it. This is
+    /// useful e.g. to caption individual figures. This will overwrite any
+    /// previous [`AxisKey::TitleStyle`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::axis::Axis;
+    ///
+    /// let mut axis = Axis::new();
+    /// axis.set_title_below("(a) Linear growth");
+    /// ```
+    pub fn set_title_below<S: Into<String>>(&mut self, title: S) {
+        self.add_key(AxisKey::Title(title.into()));
+        self.add_key(AxisKey::TitleStyle(vec![
+            String::from("at={(0.5,0)}"),
+            String::from("anchor=north"),
+            String::from("yshift=-3em"),
+        ]));
+    }
+    /// Set the font size of the title of the axis environment. This will
+    /// overwrite any previous [`AxisKey::TitleStyle`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::axis::{Axis, FontSize};
+    ///
+    /// let mut axis = Axis::new();
+    /// axis.set_title("Big title");
+    /// axis.set_title_font_size(FontSize::Large);
+    /// ```
+    pub fn set_title_font_size(&mut self, size: FontSize) {
+        self.add_key(AxisKey::TitleStyle(vec![format!("font={size}")]));
+    }
     /// Set the label of the *x* axis. This can be valid LaTeX e.g. inline math.
     ///
     /// # Examples
@@ -162,6 +558,433 @@ impl Axis {
     pub fn set_y_label<S: Into<String>>(&mut self, label: S) {
         self.add_key(AxisKey::YLabel(label.into()));
     }
+    /// Set the label of the colorbar. This can be valid LaTeX e.g. inline
+    /// math. The label is added to the [`AxisKey::ColorbarStyle`], replacing
+    /// only a previous label. It is set as `xlabel` if the axis already has a
+    /// horizontal [`AxisKey::ColorbarPosition`] and as `ylabel` otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::axis::Axis;
+    ///
+    /// let mut axis = Axis::new();
+    /// axis.set_colorbar_label("$T$~[K]");
+    /// ```
+    pub fn set_colorbar_label(&mut self, label: &str) {
+        let horizontal = self.keys.iter().any(|k| {
+            matches!(
+                k,
+                AxisKey::ColorbarPosition(ColorbarPos::Top | ColorbarPos::Bottom)
+            )
+        });
+        let entry = if horizontal {
+            format!("xlabel={{{label}}}")
+        } else {
+            format!("ylabel={{{label}}}")
+        };
+        self.merge_colorbar_style([entry], true);
+    }
+    /// Do not draw the legend inside the axis; store it under `name` instead.
+    /// The legend can then be shared by several axes and placed anywhere with
+    /// `\ref{name}` e.g. with [`Picture::append_raw`]. Note that `\ref`
+    /// requires compiling the document twice. This will overwrite any previous
+    /// [`AxisKey::LegendToName`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::axis::Axis;
+    ///
+    /// let mut axis = Axis::new();
+    /// axis.set_legend_to_name("named-legend");
+    /// ```
+    pub fn set_legend_to_name(&mut self, name: &str) {
+        self.add_key(AxisKey::LegendToName(String::from(name)));
+    }
+    /// Set the label of the *y* axis and write it horizontally instead of
+    /// rotated along the axis. Lines of the label can be separated with `\\`
+    /// to keep it narrow. This will overwrite any previous
+    /// [`AxisKey::YLabelStyle`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::axis::Axis;
+    ///
+    /// let mut axis = Axis::new();
+    /// axis.set_y_label_horizontal("Height\\\\(m)");
+    /// ```
+    pub fn set_y_label_horizontal<S: Into<String>>(&mut self, label: S) {
+        self.add_key(AxisKey::YLabel(label.into()));
+        self.add_key(AxisKey::YLabelStyle(vec![
+            String::from("rotate=-90"),
+            String::from("align=center"),
+        ]));
+    }
+    /// Right-align the tick labels of the *y* axis inside a box of the given
+    /// `width`. The width should be large enough to fit the longest label.
+    /// This will overwrite any previous [`AxisKey::YTickLabelStyle`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::{axis::Axis, dimension::Dimension};
+    ///
+    /// let mut axis = Axis::new();
+    /// axis.set_y_tick_labels_right_aligned(Dimension::Cm(1.0));
+    /// ```
+    pub fn set_y_tick_labels_right_aligned(&mut self, width: Dimension) {
+        self.add_key(AxisKey::YTickLabelStyle(vec![
+            format!("text width={width}"),
+            String::from("align=right"),
+        ]));
+    }
+    /// Write the tick labels of the *y* axis in fixed point notation (instead
+    /// of e.g. scientific notation) rounded to the given number of decimal
+    /// `digits`. This will overwrite any previous
+    /// [`AxisKey::YTickLabelStyle`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::axis::Axis;
+    ///
+    /// let mut axis = Axis::new();
+    /// axis.set_y_tick_precision(2);
+    /// ```
+    pub fn set_y_tick_precision(&mut self, digits: u32) {
+        self.add_key(AxisKey::YTickLabelStyle(vec![
+            String::from("/pgf/number format/fixed"),
+            format!("/pgf/number format/precision={digits}"),
+        ]));
+    }
+    /// Set the width of the axis, and derive its height such that
+    /// `width / height = ratio` (e.g. `1.6` for a landscape figure). Both
+    /// dimensions apply only to the axis itself i.e. this also sets
+    /// [`AxisKey::ScaleOnlyAxis`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::{axis::Axis, dimension::Dimension};
+    ///
+    /// let mut axis = Axis::new();
+    /// axis.set_aspect_ratio(Dimension::Cm(8.0), 1.6);
+    /// ```
+    pub fn set_aspect_ratio(&mut self, width: Dimension, ratio: f64) {
+        self.add_key(AxisKey::Width(width));
+        self.add_key(AxisKey::Height(width / ratio));
+        self.add_key(AxisKey::ScaleOnlyAxis(true));
+    }
+    /// Start the *y* axis at zero, even when all the data is positive. By
+    /// default the limits fit the data, which cuts the bottom of vertical bar
+    /// plots (see [`Type2D::YBar`]). This will overwrite any previous
+    /// [`AxisKey::YMin`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::axis::Axis;
+    ///
+    /// let mut axis = Axis::new();
+    /// axis.set_bar_baseline_zero();
+    /// ```
+    pub fn set_bar_baseline_zero(&mut self) {
+        self.add_key(AxisKey::YMin(0.0));
+    }
+    /// Use a logarithmic *x* axis with the given `base`. This will overwrite
+    /// any previous [`AxisKey::XMode`] and [`AxisKey::LogBasisX`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::axis::Axis;
+    ///
+    /// let mut axis = Axis::new();
+    /// axis.set_log_x(10.0);
+    /// ```
+    pub fn set_log_x(&mut self, base: f64) {
+        self.add_key(AxisKey::XMode(Scale::Log));
+        self.add_key(AxisKey::LogBasisX(base));
+    }
+    /// Use a logarithmic *y* axis with the given `base`. This will overwrite
+    /// any previous [`AxisKey::YMode`] and [`AxisKey::LogBasisY`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::axis::Axis;
+    ///
+    /// let mut axis = Axis::new();
+    /// axis.set_log_y(2.0);
+    /// ```
+    pub fn set_log_y(&mut self, base: f64) {
+        self.add_key(AxisKey::YMode(Scale::Log));
+        self.add_key(AxisKey::LogBasisY(base));
+    }
+    /// Set the limits of the *x* axis. This will overwrite any previous
+    /// [`AxisKey::XMin`] and [`AxisKey::XMax`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::axis::Axis;
+    ///
+    /// let mut axis = Axis::new();
+    /// axis.set_x_limits(-1.0, 1.0);
+    /// ```
+    pub fn set_x_limits(&mut self, xmin: f64, xmax: f64) {
+        self.add_key(AxisKey::XMin(xmin));
+        self.add_key(AxisKey::XMax(xmax));
+    }
+    /// Set the limits of the *y* axis. This will overwrite any previous
+    /// [`AxisKey::YMin`] and [`AxisKey::YMax`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::axis::Axis;
+    ///
+    /// let mut axis = Axis::new();
+    /// axis.set_y_limits(0.0, 10.0);
+    /// ```
+    pub fn set_y_limits(&mut self, ymin: f64, ymax: f64) {
+        self.add_key(AxisKey::YMin(ymin));
+        self.add_key(AxisKey::YMax(ymax));
+    }
+    /// Set the limits of both the *x* and *y* axes. This will overwrite any
+    /// previous [`AxisKey::XMin`], [`AxisKey::XMax`], [`AxisKey::YMin`], and
+    /// [`AxisKey::YMax`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::axis::Axis;
+    ///
+    /// let mut axis = Axis::new();
+    /// axis.set_limits(-1.0, 1.0, 0.0, 10.0);
+    /// ```
+    pub fn set_limits(&mut self, xmin: f64, xmax: f64, ymin: f64, ymax: f64) {
+        self.set_x_limits(xmin, xmax);
+        self.set_y_limits(ymin, ymax);
+    }
+    /// Show only the `ymin` to `ymax` range of the *y* axis, breaking the
+    /// plots wherever they leave this range. This correctly renders functions
+    /// with vertical asymptotes (e.g. `tan(x)`), and sets the following keys:
+    ///
+    /// - `AxisKey::RestrictYToDomain(ymin, ymax)`
+    /// - `AxisKey::UnboundedCoords(UnboundedCoords::Jump)`
+    /// - `AxisKey::YMin(ymin)`
+    /// - `AxisKey::YMax(ymax)`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::axis::Axis;
+    ///
+    /// let mut axis = Axis::new();
+    /// axis.clip_to_y_range(-10.0, 10.0);
+    /// ```
+    pub fn clip_to_y_range(&mut self, ymin: f64, ymax: f64) {
+        self.add_key(AxisKey::RestrictYToDomain(ymin, ymax));
+        self.add_key(AxisKey::UnboundedCoords(UnboundedCoords::Jump));
+        self.set_y_limits(ymin, ymax);
+    }
+    /// Place a panel label (e.g. `(a)`) inside the given corner of the axis.
+    /// This is useful to refer to individual axes of a multi-panel figure.
+    /// This will overwrite any previous [`AxisKey::ExtraDescription`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::axis::{Axis, Corner};
+    ///
+    /// let mut axis = Axis::new();
+    /// axis.set_panel_label("(a)", Corner::NorthWest);
+    /// ```
+    pub fn set_panel_label(&mut self, label: &str, corner: Corner) {
+        let (x, y) = corner.rel_axis_cs();
+        self.add_key(AxisKey::ExtraDescription(format!(
+            "\\node[anchor={corner}] at (rel axis cs:{x},{y}) {{{label}}};"
+        )));
+    }
+    /// Write `text` at a position relative to the axis box, regardless of the
+    /// range of the data. The position `(x_rel, y_rel)` is `(0.0, 0.0)` at the
+    /// lower left corner and `(1.0, 1.0)` at the upper right corner of the
+    /// axis. The text can be valid LaTeX e.g. inline math. Every call adds a
+    /// new annotation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::axis::Axis;
+    ///
+    /// let mut axis = Axis::new();
+    /// axis.add_text_annotation(0.5, 0.9, "$R^2 = 0.98$");
+    /// ```
+    pub fn add_text_annotation(&mut self, x_rel: f64, y_rel: f64, text: &str) {
+        self.annotations.push(format!(
+            "\\node at (rel axis cs:{x_rel},{y_rel}) {{{text}}};"
+        ));
+    }
+    /// Draw subtle (light gray and dashed) grid lines at the major and minor
+    /// ticks, below the plots. This sets the following keys:
+    ///
+    /// - `AxisKey::Grid(Grid::Both)`
+    /// - `AxisKey::GridStyle(vec!["gray!20", "dashed"])`
+    /// - `AxisKey::AxisOnTop(false)`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::axis::Axis;
+    ///
+    /// let mut axis = Axis::new();
+    /// axis.set_subtle_grid();
+    /// ```
+    pub fn set_subtle_grid(&mut self) {
+        self.add_key(AxisKey::Grid(Grid::Both));
+        self.add_key(AxisKey::GridStyle(vec![
+            String::from("gray!20"),
+            String::from("dashed"),
+        ]));
+        self.add_key(AxisKey::AxisOnTop(false));
+    }
+    /// Make the data fill the whole axis, drawing the axis lines on top of the
+    /// plots. This sets the following keys:
+    ///
+    /// - `AxisKey::EnlargeLimits(false)`
+    /// - `AxisKey::AxisOnTop(true)`
+    /// - `AxisKey::Clip(true)`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::axis::Axis;
+    ///
+    /// let mut axis = Axis::new();
+    /// axis.tight_layout();
+    /// ```
+    pub fn tight_layout(&mut self) {
+        self.add_key(AxisKey::EnlargeLimits(false));
+        self.add_key(AxisKey::AxisOnTop(true));
+        self.add_key(AxisKey::Clip(true));
+    }
+    /// Place one tick at each *x* coordinate of the data with the given
+    /// `labels`, and rotate the labels 45° only if any of them is longer than
+    /// `threshold_chars` characters. This sets `AxisKey::XTick(Ticks::Data)`,
+    /// [`AxisKey::XTickLabels`], and (only when rotating)
+    /// [`AxisKey::XTickLabelStyle`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::axis::Axis;
+    ///
+    /// let mut axis = Axis::new();
+    /// axis.auto_rotate_x_ticks(&["January", "February", "March"], 5);
+    /// ```
+    pub fn auto_rotate_x_ticks(&mut self, labels: &[&str], threshold_chars: usize) {
+        self.add_key(AxisKey::XTick(Ticks::Data));
+        self.add_key(AxisKey::XTickLabels(
+            labels.iter().map(|label| label.to_string()).collect(),
+        ));
+        if labels
+            .iter()
+            .any(|label| label.chars().count() > threshold_chars)
+        {
+            self.add_key(AxisKey::XTickLabelStyle(vec![
+                String::from("rotate=45"),
+                String::from("anchor=east"),
+            ]));
+        }
+    }
+    /// Use a logarithmic *y* axis if the data of all the plots is strictly
+    /// positive and spans more than `orders_of_magnitude` orders of magnitude
+    /// i.e. `log10(max / min) > orders_of_magnitude`. Otherwise, the axis is
+    /// left unchanged. A threshold of `3.0` is a good default.
+    ///
+    /// Only the [`AxisKey::YMode`] is set; the base of the logarithm is left
+    /// to `pgfplots`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::axis::{plot::Plot2D, Axis};
+    ///
+    /// let plot = Plot2D::from_xy(&[1.0, 2.0], &[0.1, 1000.0]).unwrap();
+    /// let mut axis = Axis::from(plot);
+    /// axis.auto_scale(3.0);
+    /// ```
+    pub fn auto_scale(&mut self, orders_of_magnitude: f64) {
+        let mut values = self.plots.iter().flat_map(|plot| {
+            plot.coordinates
+                .iter()
+                .map(|c| c.y)
+                .chain(plot.symbolic_coordinates.iter().map(|c| c.y))
+        });
+
+        let (mut min, mut max) = (f64::INFINITY, f64::NEG_INFINITY);
+        let strictly_positive = values.all(|y| {
+            min = min.min(y);
+            max = max.max(y);
+            y > 0.0
+        });
+
+        if strictly_positive && min <= max && (max / min).log10() > orders_of_magnitude {
+            self.add_key(AxisKey::YMode(Scale::Log));
+        }
+    }
+    /// Remove all the keys, plots, and annotations of the axis. The allocated
+    /// memory is kept to be reused.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::axis::{plot::Plot2D, Axis};
+    ///
+    /// let mut axis = Axis::from(Plot2D::new());
+    /// axis.clear();
+    /// assert!(axis.plots.is_empty());
+    /// ```
+    pub fn clear(&mut self) {
+        self.keys.clear();
+        self.plots.clear();
+        self.annotations.clear();
+    }
+    /// Return the total number of coordinates (numeric and symbolic) in all
+    /// the plots of the axis. This is cheap to compute, and it is useful to
+    /// decide e.g. whether the data should be downsampled before compiling.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::axis::{plot::Plot2D, Axis};
+    ///
+    /// let mut axis = Axis::new();
+    /// axis.push_plot(Plot2D::from_xy(&[1.0, 2.0], &[3.0, 4.0]).unwrap());
+    /// axis.push_plot(Plot2D::from_xy(&[1.0], &[3.0]).unwrap());
+    /// assert_eq!(axis.num_coordinates(), 3);
+    /// ```
+    pub fn num_coordinates(&self) -> usize {
+        self.plots
+            .iter()
+            .map(|plot| plot.coordinates.len() + plot.symbolic_coordinates.len())
+            .sum()
+    }
+    // Whether any plot of the axis requires the `fillbetween` library.
+    pub(crate) fn requires_fillbetween(&self) -> bool {
+        self.plots.iter().any(Plot2D::requires_fillbetween)
+    }
+    // Colors used by the keys of the axis and its plots.
+    pub(crate) fn colors(&self) -> Vec<&Color> {
+        self.keys
+            .iter()
+            .flat_map(AxisKey::colors)
+            .chain(self.plots.iter().flat_map(Plot2D::colors))
+            .collect()
+    }
     /// Add a key to control the appearance of the axis. This will overwrite
     /// any previous mutually exclusive key.
     ///
@@ -174,22 +997,91 @@ impl Axis {
     /// axis.add_key(AxisKey::YMode(Log));
     /// ```
     pub fn add_key(&mut self, key: AxisKey) {
+        let had_top_colorbar = self.has_top_colorbar();
         match key {
             AxisKey::Custom(_) => (),
             _ => {
-                if let Some(index) = self
-                    .keys
+                if let Some(index) = self.keys.iter().position(|k| {
+                    std::mem::discriminant(k) == std::mem::discriminant(&key)
+                        || (k.is_enlarge_y_limits() && key.is_enlarge_y_limits())
+                }) {
+                    self.keys.remove(index);
+                }
+            }
+        }
+        self.keys.push(key);
+
+        // A colorbar above the axis is placed through the colorbar style so
+        // that it composes with the style set by the user.
+        let has_top_colorbar = self.has_top_colorbar();
+        if has_top_colorbar {
+            self.merge_colorbar_style(TOP_COLORBAR_STYLE.map(String::from), false);
+        } else if had_top_colorbar {
+            self.remove_top_colorbar_style();
+        }
+    }
+    fn has_top_colorbar(&self) -> bool {
+        self.keys
+            .iter()
+            .any(|k| matches!(k, AxisKey::ColorbarPosition(ColorbarPos::Top)))
+    }
+    // Add `entries` to the colorbar style (creating it if needed). Entries
+    // that set an already present key replace it only if `overwrite` is true.
+    fn merge_colorbar_style<I>(&mut self, entries: I, overwrite: bool)
+    where
+        I: IntoIterator<Item = String>,
+    {
+        let index = match self
+            .keys
+            .iter()
+            .position(|k| matches!(k, AxisKey::ColorbarStyle(_)))
+        {
+            Some(index) => index,
+            None => {
+                self.keys.push(AxisKey::ColorbarStyle(Vec::new()));
+                self.keys.len() - 1
+            }
+        };
+        if let AxisKey::ColorbarStyle(values) = &mut self.keys[index] {
+            for entry in entries {
+                match values
                     .iter()
-                    .position(|k| std::mem::discriminant(k) == std::mem::discriminant(&key))
+                    .position(|v| style_key_name(v) == style_key_name(&entry))
                 {
+                    Some(i) if overwrite => values[i] = entry,
+                    Some(_) => (),
+                    None => values.push(entry),
+                }
+            }
+        }
+    }
+    // Undo the placement of a colorbar above the axis; the colorbar style is
+    // removed altogether if nothing else is left in it.
+    fn remove_top_colorbar_style(&mut self) {
+        if let Some(index) = self
+            .keys
+            .iter()
+            .position(|k| matches!(k, AxisKey::ColorbarStyle(_)))
+        {
+            if let AxisKey::ColorbarStyle(values) = &mut self.keys[index] {
+                values.retain(|v| !TOP_COLORBAR_STYLE.contains(&v.as_str()));
+                if values.is_empty() {
                     self.keys.remove(index);
                 }
             }
         }
-        self.keys.push(key);
     }
 }
 
+// Colorbar style entries that place a `ColorbarPos::Top` colorbar above the
+// axis.
+const TOP_COLORBAR_STYLE: [&str; 3] = ["at={(0.5,1.03)}", "anchor=south", "xticklabel pos=upper"];
+
+// Name of the key set by a style entry e.g. `ylabel` for `ylabel={$T$}`.
+fn style_key_name(entry: &str) -> &str {
+    entry.split('=').next().unwrap_or(entry).trim()
+}
+
 /// Control the scaling of an axis.
 #[derive(Clone, Copy, Debug)]
 pub enum Scale {
@@ -207,5 +1099,244 @@ impl fmt::Display for Scale {
     }
 }
 
+/// Corner of an [`Axis`].
+#[derive(Clone, Copy, Debug)]
+pub enum Corner {
+    /// Top left corner.
+    NorthWest,
+    /// Top right corner.
+    NorthEast,
+    /// Bottom left corner.
+    SouthWest,
+    /// Bottom right corner.
+    SouthEast,
+}
+impl fmt::Display for Corner {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Corner::NorthWest => write!(f, "north west"),
+            Corner::NorthEast => write!(f, "north east"),
+            Corner::SouthWest => write!(f, "south west"),
+            Corner::SouthEast => write!(f, "south east"),
+        }
+    }
+}
+impl Corner {
+    // Position of the corner in `rel axis cs` coordinates.
+    fn rel_axis_cs(&self) -> (f64, f64) {
+        match self {
+            Corner::NorthWest => (0.0, 1.0),
+            Corner::NorthEast => (1.0, 1.0),
+            Corner::SouthWest => (0.0, 0.0),
+            Corner::SouthEast => (1.0, 0.0),
+        }
+    }
+}
+
+/// Control the position of the colorbar.
+#[derive(Clone, Copy, Debug)]
+pub enum ColorbarPos {
+    /// Vertical colorbar to the right of the axis.
+    Right,
+    /// Vertical colorbar to the left of the axis.
+    Left,
+    /// Horizontal colorbar above the axis. The colorbar is moved above the
+    /// axis by adding `at`, `anchor` and `xticklabel pos` entries to the
+    /// [`AxisKey::ColorbarStyle`] of the [`Axis`]; entries for the same keys
+    /// set by the user take precedence.
+    Top,
+    /// Horizontal colorbar below the axis.
+    Bottom,
+}
+impl fmt::Display for ColorbarPos {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ColorbarPos::Right => write!(f, "colorbar right"),
+            ColorbarPos::Left => write!(f, "colorbar left"),
+            ColorbarPos::Top | ColorbarPos::Bottom => write!(f, "colorbar horizontal"),
+        }
+    }
+}
+
+/// Control how unbounded coordinates are handled.
+#[derive(Clone, Copy, Debug)]
+pub enum UnboundedCoords {
+    /// Skip the unbounded coordinates, connecting their neighbours.
+    Discard,
+    /// Interrupt the plot at the unbounded coordinates.
+    Jump,
+}
+impl fmt::Display for UnboundedCoords {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            UnboundedCoords::Discard => write!(f, "discard"),
+            UnboundedCoords::Jump => write!(f, "jump"),
+        }
+    }
+}
+
+/// Control the positions of the ticks of an axis.
+#[derive(Clone, Debug)]
+pub enum Ticks {
+    /// One tick at each coordinate of the data.
+    Data,
+    /// One tick at each of the given values.
+    Values(Vec<f64>),
+}
+impl fmt::Display for Ticks {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Ticks::Data => write!(f, "data"),
+            Ticks::Values(values) => {
+                let values: Vec<String> = values.iter().map(f64::to_string).collect();
+                write!(f, "{{{}}}", values.join(", "))
+            }
+        }
+    }
+}
+
+/// Map from scalar values into colors.
+///
+/// The colors are evenly distributed, i.e. the smallest value is mapped to the
+/// first color, the largest value is mapped to the last color, and values in
+/// between are linearly interpolated.
+#[derive(Clone, Debug)]
+pub struct Colormap {
+    name: String,
+    colors: Vec<Color>,
+}
+impl Colormap {
+    /// Create a new colormap from a list of colors. The `name` must be
+    /// different from any predefined colormap (e.g. `hot` or `viridis`), and
+    /// at least two colors are required by PGFPlots.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::axis::{
+    ///     plot::color::PredefinedColor::{Blue, Red},
+    ///     Axis, AxisKey, Colormap,
+    /// };
+    ///
+    /// let mut axis = Axis::new();
+    /// axis.add_key(AxisKey::DefineColormap(Colormap::custom("mymap", [Red, Blue])));
+    /// ```
+    pub fn custom<S, I, C>(name: S, colors: I) -> Self
+    where
+        S: Into<String>,
+        I: IntoIterator<Item = C>,
+        C: Into<Color>,
+    {
+        Self {
+            name: name.into(),
+            colors: colors.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+/// Control the size of text. Each variant corresponds to the LaTeX command
+/// with the same capitalization e.g. [`FontSize::UpperLarge`] is `\LARGE`.
+#[derive(Clone, Copy, Debug)]
+pub enum FontSize {
+    /// `\tiny`
+    Tiny,
+    /// `\scriptsize`
+    ScriptSize,
+    /// `\footnotesize`
+    FootnoteSize,
+    /// `\small`
+    Small,
+    /// `\normalsize`
+    NormalSize,
+    /// `\large`
+    LowerLarge,
+    /// `\Large`
+    Large,
+    /// `\LARGE`
+    UpperLarge,
+    /// `\huge`
+    LowerHuge,
+    /// `\Huge`
+    Huge,
+}
+impl fmt::Display for FontSize {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FontSize::Tiny => write!(f, "\\tiny"),
+            FontSize::ScriptSize => write!(f, "\\scriptsize"),
+            FontSize::FootnoteSize => write!(f, "\\footnotesize"),
+            FontSize::Small => write!(f, "\\small"),
+            FontSize::NormalSize => write!(f, "\\normalsize"),
+            FontSize::LowerLarge => write!(f, "\\large"),
+            FontSize::Large => write!(f, "\\Large"),
+            FontSize::UpperLarge => write!(f, "\\LARGE"),
+            FontSize::LowerHuge => write!(f, "\\huge"),
+            FontSize::Huge => write!(f, "\\Huge"),
+        }
+    }
+}
+
+/// Control the direction of an axis.
+#[derive(Clone, Copy, Debug)]
+pub enum AxisDir {
+    /// Values increase from left to right (*x* axis) or from bottom to top
+    /// (*y* axis).
+    Normal,
+    /// Values increase from right to left (*x* axis) or from top to bottom
+    /// (*y* axis).
+    Reverse,
+}
+impl fmt::Display for AxisDir {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AxisDir::Normal => write!(f, "normal"),
+            AxisDir::Reverse => write!(f, "reverse"),
+        }
+    }
+}
+
+/// Control at which ticks the grid lines of an axis are drawn.
+#[derive(Clone, Copy, Debug)]
+pub enum Grid {
+    /// No grid lines.
+    None,
+    /// Grid lines at the major ticks.
+    Major,
+    /// Grid lines at the minor ticks.
+    Minor,
+    /// Grid lines at both the major and minor ticks.
+    Both,
+}
+impl fmt::Display for Grid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Grid::None => write!(f, "none"),
+            Grid::Major => write!(f, "major"),
+            Grid::Minor => write!(f, "minor"),
+            Grid::Both => write!(f, "both"),
+        }
+    }
+}
+
+/// Control the horizontal alignment of text.
+#[derive(Clone, Copy, Debug)]
+pub enum Align {
+    /// Align the text to the left.
+    Left,
+    /// Align the text to the right.
+    Right,
+    /// Center the text.
+    Center,
+}
+impl fmt::Display for Align {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Align::Left => write!(f, "left"),
+            Align::Right => write!(f, "right"),
+            Align::Center => write!(f, "center"),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests;
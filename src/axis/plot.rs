@@ -1,11 +1,21 @@
-use crate::axis::plot::coordinate::Coordinate2D;
+use crate::axis::plot::{
+    color::Color,
+    coordinate::{Coordinate2D, SymbolicCoordinate2D},
+};
+use crate::dimension::Dimension;
 use std::fmt;
+use thiserror::Error;
 
 // Only imported for documentation. If you notice that this is no longer the
 // case, please change it.
 #[allow(unused_imports)]
-use crate::{Axis, Picture};
+use crate::{
+    axis::{Axis, AxisKey},
+    Picture,
+};
 
+/// Colors of the elements inside a plot.
+pub mod color;
 /// Coordinates inside a plot.
 pub mod coordinate;
 
@@ -38,6 +48,50 @@ pub enum PlotKey {
     /// Note that error bars won't be drawn unless [`PlotKey::YError`] is also
     /// set.
     YErrorDirection(ErrorDirection),
+    /// Control the marker drawn at each coordinate.
+    Marker(Marker),
+    /// Draw only markers. This is a shortcut for
+    /// `PlotKey::Type2D(Type2D::OnlyMarks)`, and it is mutually exclusive with
+    /// [`PlotKey::Type2D`].
+    OnlyMarks,
+    /// Fill the area enclosed by the plot with a color.
+    Fill(Color),
+    /// Draw a surface colored by the colormap of the [`Axis`]. This is
+    /// mutually exclusive with [`PlotKey::Mesh`] and [`PlotKey::Type2D`].
+    Surf,
+    /// Draw a wireframe colored by the colormap of the [`Axis`]. This is
+    /// mutually exclusive with [`PlotKey::Surf`] and [`PlotKey::Type2D`].
+    Mesh,
+    /// Control whether the plot connects the neighbours of unbounded
+    /// coordinates (e.g. a NaN *y* value), or is interrupted at them. This is
+    /// the `unbounded coords` key (`discard` when `true`, and `jump` when
+    /// `false`) applied only to this plot.
+    ConnectGaps(bool),
+    /// Control the style of the labels drawn next to each coordinate e.g.
+    /// `vec!["rotate=90", "anchor=west"]`. Note that these labels are only
+    /// drawn if they are enabled (e.g. with
+    /// `PlotKey::Custom("nodes near coords".into())`).
+    NodesNearCoordsStyle(Vec<String>),
+    /// Color each marker according to the colormap of the [`Axis`], using the
+    /// given source as the value mapped to a color.
+    Scatter(ScatterSrc),
+    /// Style of the markers of each class of a [`PlotKey::Scatter`] plot with
+    /// `ScatterSrc::ExplicitSymbolic` e.g.
+    /// `vec![("a".into(), vec!["mark=o".into(), "red".into()])]`.
+    ScatterClasses(Vec<(String, Vec<String>)>),
+    /// Control the size (radius) of the markers of the plot. Unlike
+    /// [`MarkOption::Scale`], this sets an absolute size.
+    MarkSize(Dimension),
+    /// Draw the coordinates as a matrix of cells colored by the colormap of
+    /// the [`Axis`] e.g. a heatmap of [`Plot2D::hist2d`]. The coordinates
+    /// have to be ordered row by row (the *x* value varies fastest), and
+    /// `cols` is the number of coordinates in each row. The value mapped to a
+    /// color is the point meta (e.g. [`ScatterSrc::Explicit`]). This is
+    /// mutually exclusive with e.g. [`PlotKey::Mesh`] and [`PlotKey::Type2D`].
+    MatrixPlot { cols: usize },
+    /// Name the path of the plot so that it can be referred to by other plots
+    /// e.g. to fill the area between two plots (see [`Plot2D::band_between`]).
+    NamePath(String),
 }
 
 impl fmt::Display for PlotKey {
@@ -49,10 +103,73 @@ impl fmt::Display for PlotKey {
             PlotKey::XErrorDirection(value) => write!(f, "error bars/x dir={value}"),
             PlotKey::YError(value) => write!(f, "error bars/y {value}"),
             PlotKey::YErrorDirection(value) => write!(f, "error bars/y dir={value}"),
+            PlotKey::Marker(value) => write!(f, "{value}"),
+            PlotKey::OnlyMarks => write!(f, "only marks"),
+            PlotKey::Fill(color) => write!(f, "fill={color}"),
+            PlotKey::Surf => write!(f, "surf"),
+            PlotKey::Mesh => write!(f, "mesh"),
+            PlotKey::ConnectGaps(true) => write!(f, "unbounded coords=discard"),
+            PlotKey::ConnectGaps(false) => write!(f, "unbounded coords=jump"),
+            PlotKey::NodesNearCoordsStyle(values) => {
+                write!(f, "nodes near coords style={{{}}}", values.join(", "))
+            }
+            PlotKey::Scatter(value) => write!(f, "scatter, scatter src={value}"),
+            PlotKey::ScatterClasses(classes) => {
+                let classes: Vec<String> = classes
+                    .iter()
+                    .map(|(name, style)| format!("{name}={{{}}}", style.join(",")))
+                    .collect();
+                write!(f, "scatter/classes={{{}}}", classes.join(", "))
+            }
+            PlotKey::MarkSize(value) => write!(f, "mark size={value}"),
+            PlotKey::MatrixPlot { cols } => write!(f, "matrix plot, mesh/cols={cols}"),
+            PlotKey::NamePath(name) => write!(f, "name path={name}"),
+        }
+    }
+}
+
+impl PlotKey {
+    // Keys that control the type of plot are mutually exclusive with each
+    // other e.g. `sharp plot` and `only marks` contradict each other.
+    fn is_plot_type(&self) -> bool {
+        matches!(
+            self,
+            PlotKey::Type2D(_)
+                | PlotKey::OnlyMarks
+                | PlotKey::Surf
+                | PlotKey::Mesh
+                | PlotKey::MatrixPlot { .. }
+        )
+    }
+    // Colors used by the key. If you add a variant that holds a `Color`,
+    // please add it here too; otherwise it won't be defined in the preamble.
+    pub(crate) fn colors(&self) -> Vec<&Color> {
+        match self {
+            PlotKey::Marker(marker) => marker
+                .options
+                .iter()
+                .filter_map(|option| match option {
+                    MarkOption::Fill(color) => Some(color),
+                    _ => None,
+                })
+                .collect(),
+            PlotKey::Fill(color) => vec![color],
+            _ => Vec::new(),
         }
     }
 }
 
+/// The error type returned when building a [`Plot2D`] from slices of
+/// different lengths.
+#[derive(Clone, Copy, Debug, Error)]
+#[error("slice has length {found}, expected {expected}")]
+pub struct LengthMismatch {
+    /// Length of the *x* slice.
+    pub expected: usize,
+    /// Length of the first slice that differs from the *x* slice.
+    pub found: usize,
+}
+
 /// Two-dimensional plot inside an [`Axis`].
 ///
 /// Adding a [`Plot2D`] to an [`Axis`] environment is equivalent to:
@@ -82,11 +199,24 @@ impl fmt::Display for PlotKey {
 #[derive(Clone, Debug, Default)]
 pub struct Plot2D {
     keys: Vec<PlotKey>,
+    prefix: Option<String>,
+    legend: Option<String>,
+    closed_cycle: bool,
+    // Names of the paths to fill between. If set, the plot has no coordinates
+    // of its own.
+    fill_between: Option<(String, String)>,
     pub coordinates: Vec<Coordinate2D>,
+    /// Coordinates with a symbolic *x* value. These are written after
+    /// [`Plot2D::coordinates`], and require [`AxisKey::SymbolicXCoords`] to be
+    /// set in the [`Axis`].
+    pub symbolic_coordinates: Vec<SymbolicCoordinate2D>,
 }
 
 impl fmt::Display for Plot2D {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(prefix) = &self.prefix {
+            writeln!(f, "\t{prefix}")?;
+        }
         write!(f, "\t\\addplot[")?;
         // If there are keys, print them one per line. It makes it easier for a
         // human to find individual keys later.
@@ -97,13 +227,29 @@ impl fmt::Display for Plot2D {
             }
             write!(f, "\t")?;
         }
-        writeln!(f, "] coordinates {{")?;
 
-        for coordinate in self.coordinates.iter() {
-            writeln!(f, "\t\t{coordinate}")?;
+        if let Some((first, second)) = &self.fill_between {
+            write!(f, "] fill between[of={first} and {second}];")?;
+        } else {
+            writeln!(f, "] coordinates {{")?;
+
+            for coordinate in self.coordinates.iter() {
+                writeln!(f, "\t\t{coordinate}")?;
+            }
+            for coordinate in self.symbolic_coordinates.iter() {
+                writeln!(f, "\t\t{coordinate}")?;
+            }
+
+            write!(f, "\t}}")?;
+            if self.closed_cycle {
+                write!(f, " \\closedcycle")?;
+            }
+            write!(f, ";")?;
         }
 
-        write!(f, "\t}};")?;
+        if let Some(legend) = &self.legend {
+            write!(f, "\n\t\\addlegendentry{{{legend}}}")?;
+        }
 
         Ok(())
     }
@@ -122,8 +268,181 @@ impl Plot2D {
     pub fn new() -> Self {
         Default::default()
     }
+    /// Creates a new, empty two-dimensional plot with space for at least
+    /// `capacity` (numeric) coordinates without reallocating.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::axis::plot::Plot2D;
+    ///
+    /// let plot = Plot2D::with_capacity(1000);
+    /// assert!(plot.coordinates.capacity() >= 1000);
+    /// ```
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            coordinates: Vec::with_capacity(capacity),
+            ..Default::default()
+        }
+    }
+    /// Creates a new two-dimensional plot with coordinates zipped from the
+    /// `x` and `y` slices. Returns an error if the slices have different
+    /// lengths.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use pgfplots::axis::plot::LengthMismatch;
+    /// # fn main() -> Result<(), LengthMismatch> {
+    /// use pgfplots::axis::plot::Plot2D;
+    ///
+    /// let plot = Plot2D::from_xy(&[1.0, 2.0], &[1.0, 4.0])?;
+    /// assert_eq!(plot.coordinates[1].y, 4.0);
+    ///
+    /// assert!(Plot2D::from_xy(&[1.0, 2.0], &[1.0]).is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_xy(x: &[f64], y: &[f64]) -> Result<Self, LengthMismatch> {
+        check_length(x.len(), y.len())?;
+
+        let mut plot = Plot2D::new();
+        plot.coordinates = x.iter().zip(y).map(|(&x, &y)| (x, y).into()).collect();
+        Ok(plot)
+    }
+    /// Creates a new two-dimensional plot with coordinates zipped from the
+    /// `x`, `y`, `error_x`, and `error_y` slices. Returns an error if the
+    /// slices have different lengths.
+    ///
+    /// Note that error bars are not drawn unless the corresponding
+    /// [`PlotKey`]s are also set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use pgfplots::axis::plot::LengthMismatch;
+    /// # fn main() -> Result<(), LengthMismatch> {
+    /// use pgfplots::axis::plot::Plot2D;
+    ///
+    /// let plot = Plot2D::from_xy_errors(&[1.0, 2.0], &[1.0, 4.0], &[0.1, 0.1], &[1.0, 2.0])?;
+    /// assert_eq!(plot.coordinates[1].error_y, Some(2.0));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_xy_errors(
+        x: &[f64],
+        y: &[f64],
+        error_x: &[f64],
+        error_y: &[f64],
+    ) -> Result<Self, LengthMismatch> {
+        for length in [y.len(), error_x.len(), error_y.len()] {
+            check_length(x.len(), length)?;
+        }
+
+        let mut plot = Plot2D::new();
+        plot.coordinates = x
+            .iter()
+            .zip(y)
+            .zip(error_x.iter().zip(error_y))
+            .map(|((&x, &y), (&error_x, &error_y))| (x, y, Some(error_x), Some(error_y)).into())
+            .collect();
+        Ok(plot)
+    }
+    /// Creates a two-dimensional histogram of `points`. The range of the
+    /// points is divided into a grid of `x_bins` by `y_bins` cells of equal
+    /// size, and the plot has one coordinate at the center of each cell (empty
+    /// cells included) with the number of points in the cell as its
+    /// [`Coordinate2D::point_meta`]. The coordinates are ordered row by row
+    /// i.e. the *x* value varies fastest. Points with a non-finite value are
+    /// ignored.
+    ///
+    /// The plot is drawn as markers colored by the count
+    /// ([`PlotKey::OnlyMarks`] and [`ScatterSrc::Explicit`]). Add
+    /// `PlotKey::MatrixPlot { cols: x_bins }` to draw a heatmap instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::axis::plot::Plot2D;
+    ///
+    /// let points = [(0.0, 0.0), (0.1, 0.2), (1.0, 1.0)];
+    /// let plot = Plot2D::hist2d(&points, 2, 2);
+    ///
+    /// assert_eq!(plot.coordinates.len(), 4);
+    /// assert_eq!(plot.coordinates[0].point_meta, Some(2.0));
+    /// ```
+    pub fn hist2d(points: &[(f64, f64)], x_bins: usize, y_bins: usize) -> Plot2D {
+        let mut plot = Plot2D::new();
+        plot.add_key(PlotKey::OnlyMarks);
+        plot.add_key(PlotKey::Scatter(ScatterSrc::Explicit));
+
+        let points: Vec<(f64, f64)> = points
+            .iter()
+            .copied()
+            .filter(|(x, y)| x.is_finite() && y.is_finite())
+            .collect();
+        if points.is_empty() || x_bins == 0 || y_bins == 0 {
+            return plot;
+        }
+
+        // (min, bin width) of each axis
+        let grid = |values: Vec<f64>, bins: usize| {
+            let min = values.iter().copied().fold(f64::INFINITY, f64::min);
+            let max = values.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+            (min, (max - min) / bins as f64)
+        };
+        let (x_min, x_width) = grid(points.iter().map(|p| p.0).collect(), x_bins);
+        let (y_min, y_width) = grid(points.iter().map(|p| p.1).collect(), y_bins);
+        // The maximum value belongs to the last bin.
+        let bin = |value: f64, min: f64, width: f64, bins: usize| {
+            if width > 0.0 {
+                (((value - min) / width) as usize).min(bins - 1)
+            } else {
+                0
+            }
+        };
+
+        let mut counts = vec![0usize; x_bins * y_bins];
+        for (x, y) in points {
+            let i = bin(x, x_min, x_width, x_bins);
+            let j = bin(y, y_min, y_width, y_bins);
+            counts[j * x_bins + i] += 1;
+        }
+
+        plot.coordinates = counts
+            .iter()
+            .enumerate()
+            .map(|(index, &count)| {
+                let (i, j) = (index % x_bins, index / x_bins);
+                let mut coordinate = Coordinate2D::from((
+                    x_min + (i as f64 + 0.5) * x_width,
+                    y_min + (j as f64 + 0.5) * y_width,
+                ));
+                coordinate.point_meta = Some(count as f64);
+                coordinate
+            })
+            .collect();
+        plot
+    }
+    // Whether the plot requires the `fillbetween` library of `pgfplots`.
+    pub(crate) fn requires_fillbetween(&self) -> bool {
+        self.fill_between.is_some()
+    }
+    // Name of the path of the plot (if any).
+    fn name_path(&self) -> Option<&str> {
+        self.keys.iter().find_map(|key| match key {
+            PlotKey::NamePath(name) => Some(name.as_str()),
+            _ => None,
+        })
+    }
+    // Colors used by the keys of the plot.
+    pub(crate) fn colors(&self) -> Vec<&Color> {
+        self.keys.iter().flat_map(PlotKey::colors).collect()
+    }
     /// Add a key to control the appearance of the plot. This will overwrite
-    /// any previous mutually exclusive key.
+    /// any previous mutually exclusive key. All the keys that control the type
+    /// of plot (e.g. [`PlotKey::Type2D`], [`PlotKey::OnlyMarks`], and
+    /// [`PlotKey::Surf`]) are mutually exclusive with each other.
     ///
     /// # Examples
     ///
@@ -137,17 +456,731 @@ impl Plot2D {
         match key {
             PlotKey::Custom(_) => (),
             _ => {
-                if let Some(index) = self
-                    .keys
-                    .iter()
-                    .position(|k| std::mem::discriminant(k) == std::mem::discriminant(&key))
-                {
+                if let Some(index) = self.keys.iter().position(|k| {
+                    std::mem::discriminant(k) == std::mem::discriminant(&key)
+                        || (k.is_plot_type() && key.is_plot_type())
+                }) {
                     self.keys.remove(index);
                 }
             }
         }
         self.keys.push(key);
     }
+    /// Return a copy of the plot with its keys replaced by `keys`. The keys
+    /// are added in order with [`Plot2D::add_key`]. This is useful to draw the
+    /// same data twice with a different style.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::axis::plot::{Plot2D, PlotKey, Type2D};
+    ///
+    /// let mut line = Plot2D::new();
+    /// line.add_key(PlotKey::Type2D(Type2D::Smooth { tension: 0.55 }));
+    /// line.coordinates = vec![(0.0, 0.0).into(), (1.0, 1.0).into()];
+    ///
+    /// let marks = line.clone_with_keys([PlotKey::OnlyMarks]);
+    /// ```
+    pub fn clone_with_keys<I: IntoIterator<Item = PlotKey>>(&self, keys: I) -> Plot2D {
+        let mut plot = self.clone();
+        plot.keys.clear();
+        for key in keys {
+            plot.add_key(key);
+        }
+        plot
+    }
+    /// Set raw TeX code (e.g. a `\pgfplotsset{...}` command) written verbatim
+    /// on its own line immediately before the `\addplot` command. This will
+    /// overwrite any previous prefix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::axis::plot::Plot2D;
+    ///
+    /// let mut plot = Plot2D::new();
+    /// plot.set_prefix(String::from("\\pgfplotsset{cycle list shift=1}"));
+    /// ```
+    pub fn set_prefix(&mut self, tex: String) {
+        self.prefix = Some(tex);
+    }
+    /// Set the entry of the plot in the legend of the [`Axis`]. This can be
+    /// valid LaTeX e.g. inline math. This will overwrite any previous entry.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::axis::plot::Plot2D;
+    ///
+    /// let mut plot = Plot2D::new();
+    /// plot.set_legend("$y = x^2$");
+    /// ```
+    pub fn set_legend<S: Into<String>>(&mut self, label: S) {
+        self.legend = Some(label.into());
+    }
+    /// Return the plot with its entry in the legend of the [`Axis`] set to
+    /// `label` (see [`Plot2D::set_legend`]).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::axis::plot::Plot2D;
+    ///
+    /// let plot = Plot2D::from_xy(&[0.0, 1.0], &[0.0, 1.0])
+    ///     .unwrap()
+    ///     .with_legend("model");
+    /// ```
+    pub fn with_legend<S: Into<String>>(mut self, label: S) -> Self {
+        self.set_legend(label);
+        self
+    }
+    /// Control whether the path of the plot is closed down to the *x* axis
+    /// (i.e. `y = 0`) with `\closedcycle`. Together with a fill color (e.g.
+    /// [`PlotKey::Fill`]), this shades the area under the curve. By default
+    /// the path is not closed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::axis::plot::{color::PredefinedColor, Plot2D, PlotKey};
+    ///
+    /// let mut plot = Plot2D::from_xy(&[0.0, 1.0, 2.0], &[0.0, 1.0, 4.0]).unwrap();
+    /// plot.add_key(PlotKey::Fill(PredefinedColor::Blue.into()));
+    /// plot.set_closed_cycle(true);
+    /// ```
+    pub fn set_closed_cycle(&mut self, closed: bool) {
+        self.closed_cycle = closed;
+    }
+    /// Return the plot with its path named `name` (see [`PlotKey::NamePath`]).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::axis::plot::Plot2D;
+    ///
+    /// let plot = Plot2D::from_xy(&[0.0, 1.0], &[0.0, 1.0])
+    ///     .unwrap()
+    ///     .with_name_path("upper");
+    /// ```
+    pub fn with_name_path<S: Into<String>>(mut self, name: S) -> Self {
+        self.add_key(PlotKey::NamePath(name.into()));
+        self
+    }
+    /// Draw the error bars of the *x* coordinates. This is equivalent to
+    /// adding both [`PlotKey::XError`] and [`PlotKey::XErrorDirection`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::axis::plot::{ErrorCharacter, ErrorDirection, Plot2D};
+    ///
+    /// let mut plot = Plot2D::new();
+    /// plot.enable_x_errors(ErrorCharacter::Absolute, ErrorDirection::Both);
+    /// ```
+    pub fn enable_x_errors(&mut self, character: ErrorCharacter, direction: ErrorDirection) {
+        self.add_key(PlotKey::XError(character));
+        self.add_key(PlotKey::XErrorDirection(direction));
+    }
+    /// Draw the error bars of the *y* coordinates. This is equivalent to
+    /// adding both [`PlotKey::YError`] and [`PlotKey::YErrorDirection`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::axis::plot::{ErrorCharacter, ErrorDirection, Plot2D};
+    ///
+    /// let mut plot = Plot2D::new();
+    /// plot.enable_y_errors(ErrorCharacter::Relative, ErrorDirection::Plus);
+    /// ```
+    pub fn enable_y_errors(&mut self, character: ErrorCharacter, direction: ErrorDirection) {
+        self.add_key(PlotKey::YError(character));
+        self.add_key(PlotKey::YErrorDirection(direction));
+    }
+    /// Return the plot with the error bars of the *x* coordinates enabled
+    /// (see [`Plot2D::enable_x_errors`]).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::axis::plot::{ErrorCharacter, ErrorDirection, Plot2D};
+    ///
+    /// let plot = Plot2D::new().with_x_errors(ErrorCharacter::Absolute, ErrorDirection::Both);
+    /// ```
+    pub fn with_x_errors(mut self, character: ErrorCharacter, direction: ErrorDirection) -> Self {
+        self.enable_x_errors(character, direction);
+        self
+    }
+    /// Return the plot with the error bars of the *y* coordinates enabled
+    /// (see [`Plot2D::enable_y_errors`]).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::axis::plot::{ErrorCharacter, ErrorDirection, Plot2D};
+    ///
+    /// let plot = Plot2D::new().with_y_errors(ErrorCharacter::Absolute, ErrorDirection::Both);
+    /// ```
+    pub fn with_y_errors(mut self, character: ErrorCharacter, direction: ErrorDirection) -> Self {
+        self.enable_y_errors(character, direction);
+        self
+    }
+    /// Return the plot with the `(x, y, error_x, error_y)` coordinates
+    /// appended after its own coordinates. Note that the error bars are only
+    /// drawn if they are enabled e.g. with [`Plot2D::with_y_errors`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::axis::plot::{ErrorCharacter, ErrorDirection, Plot2D};
+    ///
+    /// let plot = Plot2D::new()
+    ///     .with_error_coordinates([(1.0, 8.0, 0.2, 0.9), (3.0, 16.0, 0.4, 1.4)])
+    ///     .with_x_errors(ErrorCharacter::Absolute, ErrorDirection::Both)
+    ///     .with_y_errors(ErrorCharacter::Absolute, ErrorDirection::Both);
+    ///
+    /// assert_eq!(plot.coordinates.len(), 2);
+    /// assert_eq!(plot.coordinates[1].error_y, Some(1.4));
+    /// ```
+    pub fn with_error_coordinates<I>(mut self, coordinates: I) -> Self
+    where
+        I: IntoIterator<Item = (f64, f64, f64, f64)>,
+    {
+        self.coordinates
+            .extend(coordinates.into_iter().map(|(x, y, error_x, error_y)| {
+                Coordinate2D::from((x, y, Some(error_x), Some(error_y)))
+            }));
+        self
+    }
+    /// Append the coordinates of `other` after the coordinates of the plot.
+    /// The keys of `other` are discarded i.e. the plot keeps its own keys.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::axis::plot::Plot2D;
+    ///
+    /// let mut plot = Plot2D::new();
+    /// plot.coordinates.push((0.0, 0.0).into());
+    /// let mut other = Plot2D::new();
+    /// other.coordinates.push((1.0, 1.0).into());
+    ///
+    /// plot.concat(other);
+    /// assert_eq!(plot.coordinates.len(), 2);
+    /// ```
+    pub fn concat(&mut self, other: Plot2D) {
+        self.coordinates.extend(other.coordinates);
+        self.symbolic_coordinates.extend(other.symbolic_coordinates);
+    }
+    /// Return the plot with the coordinates of `other` appended after its own
+    /// coordinates. The keys of `other` are discarded i.e. the returned plot
+    /// keeps the keys of `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::axis::plot::Plot2D;
+    ///
+    /// let mut first = Plot2D::new();
+    /// first.coordinates.push((0.0, 0.0).into());
+    /// let mut second = Plot2D::new();
+    /// second.coordinates.push((1.0, 1.0).into());
+    ///
+    /// let plot = first.concatenated(second);
+    /// assert_eq!(plot.coordinates.len(), 2);
+    /// ```
+    pub fn concatenated(mut self, other: Plot2D) -> Self {
+        self.concat(other);
+        self
+    }
+    /// Reverse the order of the coordinates of the plot in place. This is
+    /// useful to close a polygon when combining two curves into a filled band.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::axis::plot::Plot2D;
+    ///
+    /// let mut plot = Plot2D::new();
+    /// plot.coordinates = vec![(0.0, 0.0).into(), (1.0, 1.0).into()];
+    ///
+    /// plot.reverse();
+    /// assert_eq!(plot.coordinates[0].x, 1.0);
+    /// ```
+    pub fn reverse(&mut self) {
+        self.coordinates.reverse();
+    }
+    /// Remove consecutive coordinates with the same *x* and *y* values,
+    /// keeping only the first coordinate of each run. This reduces the size
+    /// of the output without changing the appearance of e.g. step-like data.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::axis::plot::Plot2D;
+    ///
+    /// let mut plot = Plot2D::new();
+    /// plot.coordinates = vec![(0.0, 1.0).into(), (0.0, 1.0).into(), (1.0, 1.0).into()];
+    ///
+    /// plot.dedup_consecutive();
+    /// assert_eq!(plot.coordinates.len(), 2);
+    /// ```
+    pub fn dedup_consecutive(&mut self) {
+        self.coordinates.dedup_by(|current, previous| {
+            current.x.total_cmp(&previous.x).is_eq() && current.y.total_cmp(&previous.y).is_eq()
+        });
+    }
+    /// Replace the *x* value of each (numeric) coordinate with the value
+    /// returned by `f` e.g. to convert units. The errors are not transformed;
+    /// update them separately if needed (see [`Plot2D::set_errors`]).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::axis::plot::Plot2D;
+    ///
+    /// let mut plot = Plot2D::from_xy(&[1.0, 2.0], &[0.0, 1.0]).unwrap();
+    /// // Milliseconds to seconds
+    /// plot.map_x(|x| x / 1000.0);
+    /// assert_eq!(plot.coordinates[1].x, 0.002);
+    /// ```
+    pub fn map_x<F: FnMut(f64) -> f64>(&mut self, mut f: F) {
+        for coordinate in self.coordinates.iter_mut() {
+            coordinate.x = f(coordinate.x);
+        }
+    }
+    /// Replace the *y* value of each coordinate (numeric and symbolic) with
+    /// the value returned by `f` e.g. to plot a quantity in decibels. The
+    /// errors are not transformed; update them separately if needed (see
+    /// [`Plot2D::set_errors`]).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::axis::plot::Plot2D;
+    ///
+    /// let mut plot = Plot2D::from_xy(&[1.0, 2.0], &[1.0, 10.0]).unwrap();
+    /// plot.map_y(|y| 20.0 * y.log10());
+    /// assert_eq!(plot.coordinates[1].y, 20.0);
+    /// ```
+    pub fn map_y<F: FnMut(f64) -> f64>(&mut self, mut f: F) {
+        for coordinate in self.coordinates.iter_mut() {
+            coordinate.y = f(coordinate.y);
+        }
+        for coordinate in self.symbolic_coordinates.iter_mut() {
+            coordinate.y = f(coordinate.y);
+        }
+    }
+    /// Keep only the (numeric) coordinates for which `f` returns `true` e.g.
+    /// to exclude outliers. The order of the remaining coordinates is
+    /// preserved.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::axis::plot::Plot2D;
+    ///
+    /// let mut plot = Plot2D::new();
+    /// plot.coordinates = vec![(0.0, 1.0).into(), (1.0, 100.0).into(), (2.0, 3.0).into()];
+    ///
+    /// plot.retain(|c| c.y < 10.0);
+    /// assert_eq!(plot.coordinates.len(), 2);
+    /// ```
+    pub fn retain<F: FnMut(&Coordinate2D) -> bool>(&mut self, f: F) {
+        self.coordinates.retain(f);
+    }
+    /// Return the plot with only the (numeric) coordinates for which `f`
+    /// returns `true`. See [`Plot2D::retain`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::axis::plot::Plot2D;
+    ///
+    /// let plot = Plot2D::from_xy(&[0.0, 1.0, 2.0], &[1.0, 100.0, 3.0])
+    ///     .unwrap()
+    ///     .filtered(|c| c.y < 10.0);
+    /// assert_eq!(plot.coordinates.len(), 2);
+    /// ```
+    pub fn filtered<F: FnMut(&Coordinate2D) -> bool>(mut self, f: F) -> Self {
+        self.retain(f);
+        self
+    }
+    /// Remove the coordinates that can't be drawn on a logarithmic *y* axis
+    /// i.e. with a *y* value that is zero or negative, and return the number
+    /// of removed coordinates. PGFPlots silently drops these coordinates.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::axis::plot::Plot2D;
+    ///
+    /// let mut plot = Plot2D::new();
+    /// plot.coordinates = vec![(0.0, 1.0).into(), (1.0, 0.0).into(), (2.0, -1.0).into()];
+    ///
+    /// assert_eq!(plot.filter_nonpositive_for_log(), 2);
+    /// assert_eq!(plot.coordinates.len(), 1);
+    /// ```
+    pub fn filter_nonpositive_for_log(&mut self) -> usize {
+        let len = self.coordinates.len();
+        // NaN is kept; it is an unbounded coordinate, not a non-positive one.
+        self.coordinates
+            .retain(|coordinate| coordinate.y > 0.0 || coordinate.y.is_nan());
+        len - self.coordinates.len()
+    }
+    /// Remove all the keys, coordinates, prefix, and legend entry of the plot,
+    /// and disable [`Plot2D::set_closed_cycle`]. The allocated memory is kept
+    /// to be reused.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::axis::plot::Plot2D;
+    ///
+    /// let mut plot = Plot2D::new();
+    /// plot.coordinates.push((0.0, 0.0).into());
+    ///
+    /// plot.clear();
+    /// assert!(plot.coordinates.is_empty());
+    /// ```
+    pub fn clear(&mut self) {
+        self.keys.clear();
+        self.prefix = None;
+        self.legend = None;
+        self.closed_cycle = false;
+        self.fill_between = None;
+        self.coordinates.clear();
+        self.symbolic_coordinates.clear();
+    }
+    /// Return the plots required to draw a band filled with the `fill` color
+    /// between the `upper` and `lower` plots e.g. a confidence interval. The
+    /// returned plots are, in order, copies of `upper` and `lower` with a
+    /// [`PlotKey::NamePath`], and a `fill between` plot of both paths. The
+    /// band follows the curves as they are drawn (e.g. smoothed).
+    ///
+    /// The name path of a plot is kept if it already has one; otherwise it is
+    /// named `upper` or `lower` respectively. Use [`Plot2D::with_name_path`]
+    /// to give unique names if there are several bands in the same [`Axis`].
+    /// The `fillbetween` library is loaded automatically in the preamble of
+    /// the document generated by [`Picture::standalone_string`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::axis::{
+    ///     plot::{color::PredefinedColor, Plot2D},
+    ///     Axis,
+    /// };
+    ///
+    /// let mut upper = Plot2D::new();
+    /// upper.coordinates = vec![(0.0, 2.0).into(), (1.0, 3.0).into()];
+    /// let mut lower = Plot2D::new();
+    /// lower.coordinates = vec![(0.0, 1.0).into(), (1.0, 1.5).into()];
+    ///
+    /// let mut axis = Axis::new();
+    /// axis.plots
+    ///     .extend(Plot2D::band_between(&upper, &lower, PredefinedColor::Gray.into()));
+    /// ```
+    pub fn band_between(upper: &Plot2D, lower: &Plot2D, fill: Color) -> Vec<Plot2D> {
+        let named = |plot: &Plot2D, default: &str| {
+            let name = String::from(plot.name_path().unwrap_or(default));
+            (plot.clone().with_name_path(name.clone()), name)
+        };
+        let (upper, upper_name) = named(upper, "upper");
+        let (lower, lower_name) = named(lower, "lower");
+
+        let mut band = Plot2D::new();
+        band.add_key(PlotKey::Fill(fill));
+        band.fill_between = Some((upper_name, lower_name));
+
+        vec![upper, lower, band]
+    }
+    /// Set the errors of every coordinate from the value returned by `fx` (for
+    /// the *x* error) and `fy` (for the *y* error) on that coordinate. This
+    /// will overwrite any previous errors of the coordinates (the resulting
+    /// errors are symmetric).
+    ///
+    /// Note that error bars won't be drawn unless the corresponding
+    /// [`PlotKey::XError`]/[`PlotKey::YError`] and
+    /// [`PlotKey::XErrorDirection`]/[`PlotKey::YErrorDirection`] keys are also
+    /// set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::axis::plot::Plot2D;
+    ///
+    /// let mut plot = Plot2D::new();
+    /// plot.coordinates = vec![(1.0, 4.0).into(), (2.0, 9.0).into()];
+    /// // Poisson errors on y, and no errors on x
+    /// plot.set_errors(|_| None, |c| Some(c.y.sqrt()));
+    ///
+    /// assert_eq!(plot.coordinates[1].error_y, Some(3.0));
+    /// ```
+    pub fn set_errors<Fx, Fy>(&mut self, mut fx: Fx, mut fy: Fy)
+    where
+        Fx: FnMut(&Coordinate2D) -> Option<f64>,
+        Fy: FnMut(&Coordinate2D) -> Option<f64>,
+    {
+        for coordinate in self.coordinates.iter_mut() {
+            coordinate.error_x = fx(coordinate);
+            coordinate.error_y = fy(coordinate);
+            coordinate.error_y_minus = None;
+        }
+    }
+    /// Return a new plot where each *y* value is replaced by the centered
+    /// moving average over `window` coordinates. Near the edges the window is
+    /// shrunk to the available coordinates. The *x* values, errors, and keys
+    /// are preserved. A `window` of 0 or 1 returns an identical plot.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::axis::plot::Plot2D;
+    ///
+    /// let mut plot = Plot2D::new();
+    /// plot.coordinates = vec![(0.0, 1.0).into(), (1.0, 5.0).into(), (2.0, 3.0).into()];
+    ///
+    /// let smooth = plot.moving_average(3);
+    /// assert_eq!(smooth.coordinates[1].y, 3.0);
+    /// ```
+    pub fn moving_average(&self, window: usize) -> Plot2D {
+        let before = window.saturating_sub(1) / 2;
+        let after = window / 2;
+
+        let mut plot = self.clone();
+        for (i, coordinate) in plot.coordinates.iter_mut().enumerate() {
+            let start = i.saturating_sub(before);
+            let end = (i + after + 1).min(self.coordinates.len());
+            let neighbours = &self.coordinates[start..end];
+            coordinate.y = neighbours.iter().map(|c| c.y).sum::<f64>() / neighbours.len() as f64;
+        }
+        plot
+    }
+    /// Return a new plot where each *y* value is replaced by the running sum
+    /// of all the *y* values up to (and including) it. The *x* values, errors,
+    /// and keys are preserved. Combined with [`Type2D::ConstRight`] this draws
+    /// e.g. an empirical cumulative distribution.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::axis::plot::Plot2D;
+    ///
+    /// let mut plot = Plot2D::new();
+    /// plot.coordinates = vec![(0.0, 1.0).into(), (1.0, 2.0).into(), (2.0, 3.0).into()];
+    ///
+    /// let cumulative = plot.cumulative();
+    /// assert_eq!(cumulative.coordinates[2].y, 6.0);
+    /// ```
+    pub fn cumulative(&self) -> Plot2D {
+        let mut plot = self.clone();
+        let mut sum = 0.0;
+        for coordinate in plot.coordinates.iter_mut() {
+            sum += coordinate.y;
+            coordinate.y = sum;
+        }
+        plot
+    }
+    /// Return a new plot where each *y* value is replaced by the numerical
+    /// derivative `dy/dx` at that coordinate. Central differences are used for
+    /// interior coordinates, and forward (backward) differences for the first
+    /// (last) coordinate. The coordinates must be sorted by their *x* value.
+    /// The *x* values and keys are preserved, but the errors are removed. A
+    /// plot with a single coordinate has a derivative of NaN.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::axis::plot::Plot2D;
+    ///
+    /// let mut plot = Plot2D::new();
+    /// plot.coordinates = vec![(0.0, 0.0).into(), (1.0, 2.0).into(), (2.0, 4.0).into()];
+    ///
+    /// let derivative = plot.numeric_derivative();
+    /// assert_eq!(derivative.coordinates[1].y, 2.0);
+    /// ```
+    pub fn numeric_derivative(&self) -> Plot2D {
+        let last = self.coordinates.len().saturating_sub(1);
+
+        let mut plot = self.clone();
+        for (i, coordinate) in plot.coordinates.iter_mut().enumerate() {
+            let before = &self.coordinates[i.saturating_sub(1)];
+            let after = &self.coordinates[(i + 1).min(last)];
+            coordinate.y = (after.y - before.y) / (after.x - before.x);
+            coordinate.error_x = None;
+            coordinate.error_y = None;
+            coordinate.error_y_minus = None;
+        }
+        plot
+    }
+    /// Return a new plot where each *y* value is replaced by the numerical
+    /// integral (trapezoidal rule) from the first coordinate up to that
+    /// coordinate i.e. the first *y* value is always 0. The coordinates must
+    /// be sorted by their *x* value. The *x* values and keys are preserved,
+    /// but the errors are removed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::axis::plot::Plot2D;
+    ///
+    /// let mut plot = Plot2D::new();
+    /// plot.coordinates = vec![(0.0, 1.0).into(), (1.0, 1.0).into(), (2.0, 1.0).into()];
+    ///
+    /// let integral = plot.numeric_integral();
+    /// assert_eq!(integral.coordinates[2].y, 2.0);
+    /// ```
+    pub fn numeric_integral(&self) -> Plot2D {
+        let mut plot = self.clone();
+        let mut sum = 0.0;
+        for (i, coordinate) in plot.coordinates.iter_mut().enumerate() {
+            if i > 0 {
+                let before = &self.coordinates[i - 1];
+                let current = &self.coordinates[i];
+                sum += 0.5 * (current.y + before.y) * (current.x - before.x);
+            }
+            coordinate.y = sum;
+            coordinate.error_x = None;
+            coordinate.error_y = None;
+            coordinate.error_y_minus = None;
+        }
+        plot
+    }
+    /// Return the *y* value at `x` linearly interpolated between the two
+    /// nearest coordinates (sorted by their *x* value). Returns [`None`] if
+    /// `x` is outside the range of the data, or the plot has fewer than two
+    /// coordinates.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::axis::plot::Plot2D;
+    ///
+    /// let mut plot = Plot2D::new();
+    /// plot.coordinates = vec![(0.0, 0.0).into(), (2.0, 4.0).into()];
+    ///
+    /// assert_eq!(plot.interpolate_at(0.5), Some(1.0));
+    /// assert_eq!(plot.interpolate_at(3.0), None);
+    /// ```
+    pub fn interpolate_at(&self, x: f64) -> Option<f64> {
+        let mut points: Vec<(f64, f64)> = self.coordinates.iter().map(|c| (c.x, c.y)).collect();
+        points.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+        points.windows(2).find_map(|window| {
+            let ((x0, y0), (x1, y1)) = (window[0], window[1]);
+            if x0 <= x && x <= x1 {
+                if x0 == x1 {
+                    Some(y0)
+                } else {
+                    Some(y0 + (y1 - y0) * (x - x0) / (x1 - x0))
+                }
+            } else {
+                None
+            }
+        })
+    }
+}
+
+impl<'a> IntoIterator for &'a Plot2D {
+    type Item = &'a Coordinate2D;
+    type IntoIter = std::slice::Iter<'a, Coordinate2D>;
+
+    /// Iterate over the coordinates of the plot in insertion order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::axis::plot::Plot2D;
+    ///
+    /// let mut plot = Plot2D::new();
+    /// plot.coordinates = vec![(1.0, 1.0).into(), (2.0, 4.0).into()];
+    ///
+    /// let sum: f64 = (&plot).into_iter().map(|c| c.y).sum();
+    /// assert_eq!(sum, 5.0);
+    /// ```
+    fn into_iter(self) -> Self::IntoIter {
+        self.coordinates.iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a mut Plot2D {
+    type Item = &'a mut Coordinate2D;
+    type IntoIter = std::slice::IterMut<'a, Coordinate2D>;
+
+    /// Mutably iterate over the coordinates of the plot in insertion order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::axis::plot::Plot2D;
+    ///
+    /// let mut plot = Plot2D::new();
+    /// plot.coordinates = vec![(1.0, 1.0).into(), (2.0, 4.0).into()];
+    ///
+    /// for coordinate in &mut plot {
+    ///     coordinate.y *= 2.0;
+    /// }
+    /// assert_eq!(plot.coordinates[1].y, 8.0);
+    /// ```
+    fn into_iter(self) -> Self::IntoIter {
+        self.coordinates.iter_mut()
+    }
+}
+
+/// Return `n` evenly spaced values from `start` to `end` (both included).
+/// This follows the semantics of NumPy's `linspace` e.g. it returns only
+/// `start` if `n == 1`.
+///
+/// # Examples
+///
+/// ```
+/// use pgfplots::axis::plot::linspace;
+///
+/// assert_eq!(linspace(0.0, 1.0, 5), vec![0.0, 0.25, 0.5, 0.75, 1.0]);
+/// ```
+pub fn linspace(start: f64, end: f64, n: usize) -> Vec<f64> {
+    match n {
+        0 => Vec::new(),
+        1 => vec![start],
+        _ => {
+            let step = (end - start) / (n - 1) as f64;
+            (0..n)
+                .map(|i| {
+                    if i == n - 1 {
+                        end
+                    } else {
+                        start + step * i as f64
+                    }
+                })
+                .collect()
+        }
+    }
+}
+
+/// Return `n` values evenly spaced on a logarithmic scale from
+/// `10^start_exp` to `10^end_exp` (both included). This follows the semantics
+/// of NumPy's `logspace`.
+///
+/// # Examples
+///
+/// ```
+/// use pgfplots::axis::plot::logspace;
+///
+/// assert_eq!(logspace(0.0, 2.0, 3), vec![1.0, 10.0, 100.0]);
+/// ```
+pub fn logspace(start_exp: f64, end_exp: f64, n: usize) -> Vec<f64> {
+    linspace(start_exp, end_exp, n)
+        .into_iter()
+        .map(|exp| 10f64.powf(exp))
+        .collect()
+}
+
+fn check_length(expected: usize, found: usize) -> Result<(), LengthMismatch> {
+    if expected == found {
+        Ok(())
+    } else {
+        Err(LengthMismatch { expected, found })
+    }
 }
 
 /// Control the type of two dimensional plots.
@@ -211,6 +1244,11 @@ pub enum Type2D {
     YComb,
     /// Draw only markers.
     OnlyMarks,
+    /// Draw vertical bars between successive *x* values i.e. a histogram
+    /// where the coordinates are the bin edges. The bar from `x[i]` to
+    /// `x[i+1]` has height `y[i]`, so the *y* value of the last coordinate is
+    /// ignored (its *x* value is only the right edge of the last bin).
+    YBarInterval,
 }
 impl fmt::Display for Type2D {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -234,6 +1272,197 @@ impl fmt::Display for Type2D {
             Type2D::XComb => write!(f, "xcomb"),
             Type2D::YComb => write!(f, "ycomb"),
             Type2D::OnlyMarks => write!(f, "only marks"),
+            Type2D::YBarInterval => write!(f, "ybar interval"),
+        }
+    }
+}
+
+/// Marker drawn at each coordinate of a plot. The default marker is an open
+/// circle ([`MarkShape::O`]) without options.
+///
+/// # Examples
+///
+/// ```
+/// use pgfplots::axis::plot::{
+///     color::PredefinedColor, MarkOption, MarkShape, Marker, Plot2D, PlotKey,
+/// };
+///
+/// let mut plot = Plot2D::new();
+/// plot.add_key(PlotKey::Marker(Marker::new(
+///     MarkShape::OFilled,
+///     vec![
+///         MarkOption::Fill(PredefinedColor::Red.into()),
+///         MarkOption::FillOpacity(0.3),
+///     ],
+/// )));
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct Marker {
+    shape: MarkShape,
+    options: Vec<MarkOption>,
+}
+impl fmt::Display for Marker {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let options: Vec<String> = self.options.iter().map(|o| o.to_string()).collect();
+        write!(
+            f,
+            "mark={}, mark options={{{}}}",
+            self.shape,
+            options.join(", ")
+        )
+    }
+}
+impl Marker {
+    /// Create a new marker with the given shape and options.
+    pub fn new(shape: MarkShape, options: Vec<MarkOption>) -> Self {
+        Self { shape, options }
+    }
+    /// Check that all the options of the marker have an effect on its shape.
+    /// Returns a warning if e.g. [`MarkOption::Fill`] is applied to an open
+    /// shape such as [`MarkShape::O`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::axis::plot::{color::PredefinedColor, MarkOption, MarkShape, Marker};
+    ///
+    /// let options = vec![MarkOption::Fill(PredefinedColor::Red.into())];
+    ///
+    /// assert!(Marker::new(MarkShape::O, options.clone()).validate().is_err());
+    /// assert!(Marker::new(MarkShape::OFilled, options).validate().is_ok());
+    /// ```
+    pub fn validate(&self) -> Result<(), MarkerWarning> {
+        let has_fill = self
+            .options
+            .iter()
+            .any(|option| matches!(option, MarkOption::Fill(_)));
+        if has_fill && !self.shape.is_filled() {
+            return Err(MarkerWarning::FillOnOpenShape {
+                shape: self.shape,
+                filled: self.shape.filled(),
+            });
+        }
+
+        Ok(())
+    }
+}
+
+/// The warning type returned when a [`Marker`] has options that don't affect
+/// its shape.
+#[derive(Clone, Copy, Debug, Error)]
+#[non_exhaustive]
+pub enum MarkerWarning {
+    /// [`MarkOption::Fill`] has no effect on the open `shape`. The `filled`
+    /// variant of the shape (if there is one) should be used instead.
+    #[error("fill has no effect on open mark shape `{shape}`")]
+    FillOnOpenShape {
+        shape: MarkShape,
+        filled: Option<MarkShape>,
+    },
+}
+
+/// Control the shape of a [`Marker`]. The default shape is an open circle.
+#[derive(Clone, Copy, Debug, Default)]
+#[non_exhaustive]
+pub enum MarkShape {
+    /// Open circle.
+    #[default]
+    O,
+    /// Filled circle.
+    OFilled,
+    /// Cross.
+    X,
+    /// Plus sign.
+    Plus,
+    /// Asterisk.
+    Asterisk,
+    /// Five pointed star.
+    Star,
+    /// Open square.
+    Square,
+    /// Filled square.
+    SquareFilled,
+    /// Open triangle.
+    Triangle,
+    /// Filled triangle.
+    TriangleFilled,
+    /// Open diamond.
+    Diamond,
+    /// Filled diamond.
+    DiamondFilled,
+    /// Open pentagon.
+    Pentagon,
+    /// Filled pentagon.
+    PentagonFilled,
+}
+impl fmt::Display for MarkShape {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MarkShape::O => write!(f, "o"),
+            MarkShape::OFilled => write!(f, "*"),
+            MarkShape::X => write!(f, "x"),
+            MarkShape::Plus => write!(f, "+"),
+            MarkShape::Asterisk => write!(f, "asterisk"),
+            MarkShape::Star => write!(f, "star"),
+            MarkShape::Square => write!(f, "square"),
+            MarkShape::SquareFilled => write!(f, "square*"),
+            MarkShape::Triangle => write!(f, "triangle"),
+            MarkShape::TriangleFilled => write!(f, "triangle*"),
+            MarkShape::Diamond => write!(f, "diamond"),
+            MarkShape::DiamondFilled => write!(f, "diamond*"),
+            MarkShape::Pentagon => write!(f, "pentagon"),
+            MarkShape::PentagonFilled => write!(f, "pentagon*"),
+        }
+    }
+}
+impl MarkShape {
+    fn is_filled(&self) -> bool {
+        matches!(
+            self,
+            MarkShape::OFilled
+                | MarkShape::SquareFilled
+                | MarkShape::TriangleFilled
+                | MarkShape::DiamondFilled
+                | MarkShape::PentagonFilled
+        )
+    }
+    // Filled variant of an open shape (if there is one).
+    fn filled(&self) -> Option<MarkShape> {
+        match self {
+            MarkShape::O => Some(MarkShape::OFilled),
+            MarkShape::Square => Some(MarkShape::SquareFilled),
+            MarkShape::Triangle => Some(MarkShape::TriangleFilled),
+            MarkShape::Diamond => Some(MarkShape::DiamondFilled),
+            MarkShape::Pentagon => Some(MarkShape::PentagonFilled),
+            _ => None,
+        }
+    }
+}
+
+/// Control the appearance of a [`Marker`]. These options only affect the
+/// markers, not the lines that connect them.
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub enum MarkOption {
+    /// Scale the size of the marker.
+    Scale(f64),
+    /// Control the fill color of the marker. Note that this has no effect on
+    /// open shapes e.g. [`MarkShape::O`].
+    Fill(Color),
+    /// Control the opacity of the fill of the marker (`0.0` is fully
+    /// transparent and `1.0` is fully opaque).
+    FillOpacity(f64),
+    /// Control the opacity of the whole marker i.e. both the fill and the
+    /// border (`0.0` is fully transparent and `1.0` is fully opaque).
+    Opacity(f64),
+}
+impl fmt::Display for MarkOption {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MarkOption::Scale(value) => write!(f, "scale={value}"),
+            MarkOption::Fill(value) => write!(f, "fill={value}"),
+            MarkOption::FillOpacity(value) => write!(f, "fill opacity={value}"),
+            MarkOption::Opacity(value) => write!(f, "opacity={value}"),
         }
     }
 }
@@ -279,5 +1508,28 @@ impl fmt::Display for ErrorDirection {
     }
 }
 
+/// Control the value used to color the markers of a [`PlotKey::Scatter`] plot.
+#[derive(Clone, Copy, Debug)]
+pub enum ScatterSrc {
+    /// Explicit numeric value given for each coordinate.
+    Explicit,
+    /// Explicit symbolic value (e.g. a class name) given for each coordinate.
+    ExplicitSymbolic,
+    /// The *x* value of each coordinate.
+    X,
+    /// The *y* value of each coordinate.
+    Y,
+}
+impl fmt::Display for ScatterSrc {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ScatterSrc::Explicit => write!(f, "explicit"),
+            ScatterSrc::ExplicitSymbolic => write!(f, "explicit symbolic"),
+            ScatterSrc::X => write!(f, "x"),
+            ScatterSrc::Y => write!(f, "y"),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests;
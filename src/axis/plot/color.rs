@@ -0,0 +1,247 @@
+use std::fmt;
+use thiserror::Error;
+
+// Only imported for documentation. If you notice that this is no longer the
+// case, please change it.
+#[allow(unused_imports)]
+use crate::Picture;
+
+/// Color of the elements inside a [`Picture`].
+///
+/// # Examples
+///
+/// ```
+/// use pgfplots::axis::plot::color::{Color, PredefinedColor};
+///
+/// let color = Color::from(PredefinedColor::Red);
+/// assert_eq!(color.to_string(), "red");
+/// ```
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub enum Color {
+    /// Color that is always available in LaTeX (without additional packages or
+    /// package options).
+    Predefined(PredefinedColor),
+    /// Color with a name that is written verbatim (see [`Color::named`]).
+    Named(String),
+    /// Color from its red, green, and blue components. These colors are
+    /// defined automatically in the preamble of the document generated by
+    /// [`Picture::standalone_string`].
+    Rgb(u8, u8, u8),
+    /// Color from its cyan, magenta, yellow, and black components in the range
+    /// `0.0..=1.0` (see [`Color::from_cmyk`]). The components are rounded to
+    /// three decimal places. These colors are defined automatically in the
+    /// preamble of the document generated by [`Picture::standalone_string`].
+    Cmyk(f64, f64, f64, f64),
+}
+
+impl fmt::Display for Color {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Color::Predefined(color) => write!(f, "{color}"),
+            Color::Named(name) => write!(f, "{name}"),
+            Color::Rgb(red, green, blue) => write!(f, "rgb{red:02X}{green:02X}{blue:02X}"),
+            Color::Cmyk(cyan, magenta, yellow, black) => {
+                write!(f, "cmyk")?;
+                for component in [cyan, magenta, yellow, black] {
+                    write!(f, "{:04}", permille(*component))?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl Color {
+    /// Create a color from its name e.g. `SteelBlue`. The name is written
+    /// verbatim, so it has to be defined in the document. For example,
+    /// `SteelBlue` is one of the `svgnames` of the `xcolor` package, which
+    /// need to be enabled with `\usepackage[svgnames]{xcolor}` before
+    /// `pgfplots` is loaded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::axis::plot::color::Color;
+    ///
+    /// let color = Color::named("SteelBlue");
+    /// assert_eq!(color.to_string(), "SteelBlue");
+    /// ```
+    pub fn named<S: Into<String>>(name: S) -> Color {
+        Color::Named(name.into())
+    }
+    /// Create a color from its red, green, and blue components.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::axis::plot::color::Color;
+    ///
+    /// let steel_blue = Color::from_rgb(70, 130, 180);
+    /// ```
+    pub fn from_rgb(red: u8, green: u8, blue: u8) -> Color {
+        Color::Rgb(red, green, blue)
+    }
+    /// Create a color from its hexadecimal `0xRRGGBB` representation. Only the
+    /// 24 least significant bits are used.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::axis::plot::color::Color;
+    ///
+    /// let steel_blue = Color::from_hex(0x4682B4);
+    /// ```
+    pub fn from_hex(hex: u32) -> Color {
+        let [_, red, green, blue] = hex.to_be_bytes();
+        Color::Rgb(red, green, blue)
+    }
+    /// Create a color from its cyan, magenta, yellow, and black components
+    /// e.g. for print workflows. Returns an error if any component is not in
+    /// the range `0.0..=1.0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use pgfplots::axis::plot::color::InvalidComponent;
+    /// # fn main() -> Result<(), InvalidComponent> {
+    /// use pgfplots::axis::plot::color::Color;
+    ///
+    /// let teal = Color::from_cmyk(1.0, 0.0, 0.3, 0.5)?;
+    ///
+    /// assert!(Color::from_cmyk(1.5, 0.0, 0.0, 0.0).is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_cmyk(
+        cyan: f64,
+        magenta: f64,
+        yellow: f64,
+        black: f64,
+    ) -> Result<Color, InvalidComponent> {
+        for value in [cyan, magenta, yellow, black] {
+            if !(0.0..=1.0).contains(&value) {
+                return Err(InvalidComponent { value });
+            }
+        }
+        Ok(Color::Cmyk(cyan, magenta, yellow, black))
+    }
+    // Whether the color requires the `svgnames` option of the `xcolor`
+    // package. Named colors are assumed to need it; this is harmless if they
+    // don't.
+    pub(crate) fn requires_svgnames(&self) -> bool {
+        matches!(self, Color::Named(_))
+    }
+    // Line that defines the color in the preamble of the document (if the
+    // color is not already available in LaTeX).
+    pub(crate) fn definition(&self) -> Option<String> {
+        match self {
+            Color::Rgb(red, green, blue) => Some(format!(
+                "\\definecolor{{{self}}}{{HTML}}{{{red:02X}{green:02X}{blue:02X}}}"
+            )),
+            Color::Cmyk(cyan, magenta, yellow, black) => {
+                let components: Vec<String> = [cyan, magenta, yellow, black]
+                    .iter()
+                    .map(|component| (f64::from(permille(**component)) / 1000.0).to_string())
+                    .collect();
+                Some(format!(
+                    "\\definecolor{{{self}}}{{cmyk}}{{{}}}",
+                    components.join(",")
+                ))
+            }
+            Color::Predefined(_) | Color::Named(_) => None,
+        }
+    }
+}
+
+// Component of a color in thousandths, clamped to `0..=1000`.
+fn permille(component: f64) -> u16 {
+    (component.clamp(0.0, 1.0) * 1000.0).round() as u16
+}
+
+/// The error type returned when a component of a [`Color`] is out of range.
+#[derive(Clone, Copy, Debug, Error)]
+#[error("color component {value} is out of range")]
+pub struct InvalidComponent {
+    /// First component that is out of range.
+    pub value: f64,
+}
+
+impl From<PredefinedColor> for Color {
+    fn from(color: PredefinedColor) -> Self {
+        Color::Predefined(color)
+    }
+}
+
+/// Colors that are always available in LaTeX. These are the base colors
+/// predefined by the `xcolor` package (loaded by `pgfplots`); none of them
+/// requires the `svgnames` option of `xcolor` (unlike e.g. [`Color::named`]).
+#[derive(Clone, Copy, Debug)]
+pub enum PredefinedColor {
+    /// The `red` color predefined by `xcolor`.
+    Red,
+    /// The `green` color predefined by `xcolor`.
+    Green,
+    /// The `blue` color predefined by `xcolor`.
+    Blue,
+    /// The `cyan` color predefined by `xcolor`.
+    Cyan,
+    /// The `magenta` color predefined by `xcolor`.
+    Magenta,
+    /// The `yellow` color predefined by `xcolor`.
+    Yellow,
+    /// The `black` color predefined by `xcolor`.
+    Black,
+    /// The `gray` color predefined by `xcolor`.
+    Gray,
+    /// The `white` color predefined by `xcolor`.
+    White,
+    /// The `darkgray` color predefined by `xcolor`.
+    DarkGray,
+    /// The `lightgray` color predefined by `xcolor`.
+    LightGray,
+    /// The `brown` color predefined by `xcolor`.
+    Brown,
+    /// The `lime` color predefined by `xcolor`.
+    Lime,
+    /// The `olive` color predefined by `xcolor`.
+    Olive,
+    /// The `orange` color predefined by `xcolor`.
+    Orange,
+    /// The `pink` color predefined by `xcolor`.
+    Pink,
+    /// The `purple` color predefined by `xcolor`.
+    Purple,
+    /// The `teal` color predefined by `xcolor`.
+    Teal,
+    /// The `violet` color predefined by `xcolor`.
+    Violet,
+}
+impl fmt::Display for PredefinedColor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PredefinedColor::Red => write!(f, "red"),
+            PredefinedColor::Green => write!(f, "green"),
+            PredefinedColor::Blue => write!(f, "blue"),
+            PredefinedColor::Cyan => write!(f, "cyan"),
+            PredefinedColor::Magenta => write!(f, "magenta"),
+            PredefinedColor::Yellow => write!(f, "yellow"),
+            PredefinedColor::Black => write!(f, "black"),
+            PredefinedColor::Gray => write!(f, "gray"),
+            PredefinedColor::White => write!(f, "white"),
+            PredefinedColor::DarkGray => write!(f, "darkgray"),
+            PredefinedColor::LightGray => write!(f, "lightgray"),
+            PredefinedColor::Brown => write!(f, "brown"),
+            PredefinedColor::Lime => write!(f, "lime"),
+            PredefinedColor::Olive => write!(f, "olive"),
+            PredefinedColor::Orange => write!(f, "orange"),
+            PredefinedColor::Pink => write!(f, "pink"),
+            PredefinedColor::Purple => write!(f, "purple"),
+            PredefinedColor::Teal => write!(f, "teal"),
+            PredefinedColor::Violet => write!(f, "violet"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,159 @@
+use super::*;
+
+#[test]
+fn predefined_color_to_string() {
+    assert_eq!(PredefinedColor::Red.to_string(), String::from("red"));
+    assert_eq!(PredefinedColor::Green.to_string(), String::from("green"));
+    assert_eq!(PredefinedColor::Blue.to_string(), String::from("blue"));
+    assert_eq!(PredefinedColor::Cyan.to_string(), String::from("cyan"));
+    assert_eq!(
+        PredefinedColor::Magenta.to_string(),
+        String::from("magenta")
+    );
+    assert_eq!(PredefinedColor::Yellow.to_string(), String::from("yellow"));
+    assert_eq!(PredefinedColor::Black.to_string(), String::from("black"));
+    assert_eq!(PredefinedColor::Gray.to_string(), String::from("gray"));
+    assert_eq!(PredefinedColor::White.to_string(), String::from("white"));
+    assert_eq!(
+        PredefinedColor::DarkGray.to_string(),
+        String::from("darkgray")
+    );
+    assert_eq!(
+        PredefinedColor::LightGray.to_string(),
+        String::from("lightgray")
+    );
+    assert_eq!(PredefinedColor::Brown.to_string(), String::from("brown"));
+    assert_eq!(PredefinedColor::Lime.to_string(), String::from("lime"));
+    assert_eq!(PredefinedColor::Olive.to_string(), String::from("olive"));
+    assert_eq!(PredefinedColor::Orange.to_string(), String::from("orange"));
+    assert_eq!(PredefinedColor::Pink.to_string(), String::from("pink"));
+    assert_eq!(PredefinedColor::Purple.to_string(), String::from("purple"));
+    assert_eq!(PredefinedColor::Teal.to_string(), String::from("teal"));
+    assert_eq!(PredefinedColor::Violet.to_string(), String::from("violet"));
+}
+
+// This test is here only to let us know if we added an enum variant
+// but we forgot to add unit tests for it
+//
+// If this fails, it is because you added a new variant.
+// Please do the following:
+// 1) Add a unit test for the new variant you added (see examples below).
+// 2) AFTER doing (1), add the new variant to the match.
+#[test]
+fn colors_tested() {
+    let color = Color::Predefined(PredefinedColor::Red);
+    match color {
+        Color::Predefined(_) => (),
+        Color::Named(_) => (),
+        Color::Rgb(_, _, _) => (),
+        Color::Cmyk(_, _, _, _) => (),
+    }
+}
+
+#[test]
+fn color_predefined_to_string() {
+    assert_eq!(
+        Color::Predefined(PredefinedColor::Red).to_string(),
+        String::from("red")
+    );
+    assert_eq!(
+        Color::from(PredefinedColor::LightGray).to_string(),
+        String::from("lightgray")
+    );
+}
+
+#[test]
+fn color_named_to_string() {
+    assert_eq!(
+        Color::Named(String::from("SteelBlue")).to_string(),
+        String::from("SteelBlue")
+    );
+    assert_eq!(
+        Color::named("SteelBlue").to_string(),
+        String::from("SteelBlue")
+    );
+    assert_eq!(
+        Color::named(String::from("red!50!black")).to_string(),
+        String::from("red!50!black")
+    );
+}
+
+#[test]
+fn color_rgb_to_string() {
+    assert_eq!(
+        Color::Rgb(70, 130, 180).to_string(),
+        String::from("rgb4682B4")
+    );
+    assert_eq!(Color::Rgb(0, 0, 0).to_string(), String::from("rgb000000"));
+}
+
+#[test]
+fn color_from_rgb() {
+    assert!(matches!(
+        Color::from_rgb(70, 130, 180),
+        Color::Rgb(70, 130, 180)
+    ));
+}
+
+#[test]
+fn color_from_hex() {
+    assert!(matches!(
+        Color::from_hex(0x4682B4),
+        Color::Rgb(70, 130, 180)
+    ));
+    assert!(matches!(Color::from_hex(0xFF000001), Color::Rgb(0, 0, 1)));
+}
+
+#[test]
+fn color_cmyk_to_string() {
+    assert_eq!(
+        Color::Cmyk(0.2, 0.1, 0.0, 0.3).to_string(),
+        String::from("cmyk0200010000000300")
+    );
+    assert_eq!(
+        Color::Cmyk(1.0, 0.0, 0.0125, 0.0).to_string(),
+        String::from("cmyk1000000000130000")
+    );
+}
+
+#[test]
+fn color_from_cmyk() {
+    assert!(matches!(
+        Color::from_cmyk(0.2, 0.1, 0.0, 0.3),
+        Ok(Color::Cmyk(c, m, y, k)) if c == 0.2 && m == 0.1 && y == 0.0 && k == 0.3
+    ));
+    assert!(Color::from_cmyk(0.0, 0.0, 0.0, 0.0).is_ok());
+    assert!(Color::from_cmyk(1.0, 1.0, 1.0, 1.0).is_ok());
+
+    let error = Color::from_cmyk(0.2, -0.1, 0.0, 0.0).unwrap_err();
+    assert_eq!(error.value, -0.1);
+    assert!(Color::from_cmyk(0.2, 0.1, 1.01, 0.0).is_err());
+    assert!(Color::from_cmyk(0.2, 0.1, 0.0, f64::NAN).is_err());
+}
+
+#[test]
+fn color_requires_svgnames() {
+    assert!(!Color::Predefined(PredefinedColor::Red).requires_svgnames());
+    assert!(Color::named("SteelBlue").requires_svgnames());
+    assert!(!Color::from_hex(0x4682B4).requires_svgnames());
+    assert!(!Color::Cmyk(0.2, 0.1, 0.0, 0.3).requires_svgnames());
+}
+
+#[test]
+fn color_definition() {
+    assert!(Color::Predefined(PredefinedColor::Red)
+        .definition()
+        .is_none());
+    assert!(Color::named("SteelBlue").definition().is_none());
+    assert_eq!(
+        Color::from_hex(0x4682B4).definition().unwrap(),
+        String::from("\\definecolor{rgb4682B4}{HTML}{4682B4}")
+    );
+    assert_eq!(
+        Color::from_cmyk(0.2, 0.1, 0.0, 0.3)
+            .unwrap()
+            .definition()
+            .unwrap(),
+        String::from("\\definecolor{cmyk0200010000000300}{cmyk}{0.2,0.1,0,0.3}")
+    );
+}
@@ -1,9 +1,13 @@
 use std::fmt;
+use std::num::ParseFloatError;
 
 // Only imported for documentation. If you notice this is no longer the case,
 // please change it.
 #[allow(unused_imports)]
-use crate::axis::plot::{Plot2D, PlotKey};
+use crate::axis::{
+    plot::{Plot2D, PlotKey},
+    AxisKey,
+};
 
 /// Coordinate in a two-dimensional plot.
 #[derive(Clone, Copy, Debug)]
@@ -19,20 +23,34 @@ pub struct Coordinate2D {
     /// are only drawn if both [`PlotKey::YError`] and
     /// [`PlotKey::YErrorDirection`] are set in the [`Plot2D`].
     pub error_y: Option<f64>,
-    // What to do when `point meta=explicit` in plot?
-    // Should we add an Option<point_meta> here?
-    // Is `point meta` skipped same as error when it is not set?
+    /// Lower (negative direction) *y* error. If it is a [`Some`], the error
+    /// bars are asymmetric and [`Coordinate2D::error_y`] is only the upper
+    /// (positive direction) *y* error.
+    pub error_y_minus: Option<f64>,
+    /// Explicit value associated with the coordinate e.g. to color markers
+    /// with the colormap of the [`Axis`](crate::axis::Axis). This is only
+    /// used if the [`Plot2D`] reads explicit point meta values (e.g. with
+    /// [`ScatterSrc::Explicit`](crate::axis::plot::ScatterSrc::Explicit)).
+    pub point_meta: Option<f64>,
 }
 
 impl fmt::Display for Coordinate2D {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "({},{})", self.x, self.y)?;
 
-        if self.error_x.is_some() || self.error_y.is_some() {
+        if let Some(error_y_minus) = self.error_y_minus {
+            let error_x = self.error_x.unwrap_or(0.0);
+            let error_y = self.error_y.unwrap_or(0.0);
+            write!(f, "\t+= ({error_x},{error_y})")?;
+            write!(f, "\t-= ({error_x},{error_y_minus})")?;
+        } else if self.error_x.is_some() || self.error_y.is_some() {
             let error_x = self.error_x.unwrap_or(0.0);
             let error_y = self.error_y.unwrap_or(0.0);
             write!(f, "\t+- ({error_x},{error_y})")?;
         }
+        if let Some(point_meta) = self.point_meta {
+            write!(f, "\t[{point_meta}]")?;
+        }
 
         Ok(())
     }
@@ -59,6 +77,8 @@ impl From<(f64, f64)> for Coordinate2D {
             y: coordinate.1,
             error_x: None,
             error_y: None,
+            error_y_minus: None,
+            point_meta: None,
         }
     }
 }
@@ -85,6 +105,93 @@ impl From<(f64, f64, Option<f64>, Option<f64>)> for Coordinate2D {
             y: coordinate.1,
             error_x: coordinate.2,
             error_y: coordinate.3,
+            error_y_minus: None,
+            point_meta: None,
+        }
+    }
+}
+
+impl Coordinate2D {
+    /// Parse a coordinate from the strings of its *x* and *y* values e.g.
+    /// fields of CSV-like data. Leading and trailing white space is ignored,
+    /// and a decimal comma (e.g. `"2,5"`) is accepted as a decimal point.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::axis::plot::coordinate::Coordinate2D;
+    ///
+    /// let point = Coordinate2D::parse(" 1.0", "2,5").unwrap();
+    ///
+    /// assert_eq!(point.x, 1.0);
+    /// assert_eq!(point.y, 2.5);
+    /// assert!(Coordinate2D::parse("1.0", "abc").is_err());
+    /// ```
+    pub fn parse(x: &str, y: &str) -> Result<Coordinate2D, ParseFloatError> {
+        let parse = |value: &str| value.trim().replace(',', ".").parse::<f64>();
+        Ok((parse(x)?, parse(y)?).into())
+    }
+    /// Create a coordinate with asymmetric *y* errors from the lower and upper
+    /// bounds of `y` e.g. a confidence interval. The upper error is
+    /// `y_upper - y` and the lower error is `y - y_lower`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::axis::plot::coordinate::Coordinate2D;
+    ///
+    /// let point = Coordinate2D::from_bounds(1.0, 5.0, 4.0, 7.0);
+    ///
+    /// assert_eq!(point.error_y, Some(2.0));
+    /// assert_eq!(point.error_y_minus, Some(1.0));
+    /// assert!(point.error_x.is_none());
+    /// ```
+    pub fn from_bounds(x: f64, y: f64, y_lower: f64, y_upper: f64) -> Self {
+        Coordinate2D {
+            x,
+            y,
+            error_x: None,
+            error_y: Some(y_upper - y),
+            error_y_minus: Some(y - y_lower),
+            point_meta: None,
+        }
+    }
+}
+
+/// Coordinate with a symbolic (categorical) *x* value in a two-dimensional
+/// plot. The *x* value has to be one of the categories set with
+/// [`AxisKey::SymbolicXCoords`].
+///
+/// # Examples
+///
+/// ```
+/// use pgfplots::axis::plot::coordinate::SymbolicCoordinate2D;
+///
+/// let point: SymbolicCoordinate2D = ("apples", 5.0).into();
+///
+/// assert_eq!(point.x, "apples");
+/// assert_eq!(point.y, 5.0);
+/// ```
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub struct SymbolicCoordinate2D {
+    pub x: String,
+    pub y: f64,
+}
+
+impl fmt::Display for SymbolicCoordinate2D {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({},{})", self.x, self.y)
+    }
+}
+
+impl<S: Into<String>> From<(S, f64)> for SymbolicCoordinate2D {
+    /// Conversion from an `(x,y)` tuple into a two-dimensional coordinate with
+    /// a symbolic *x* value.
+    fn from(coordinate: (S, f64)) -> Self {
+        SymbolicCoordinate2D {
+            x: coordinate.0.into(),
+            y: coordinate.1,
         }
     }
 }
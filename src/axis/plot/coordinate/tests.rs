@@ -7,6 +7,7 @@ fn coordinate_2d_from_short_tuple() {
     assert_eq!(coord.y, -1.0);
     assert!(coord.error_x.is_none());
     assert!(coord.error_y.is_none());
+    assert!(coord.error_y_minus.is_none());
 }
 
 #[test]
@@ -50,3 +51,65 @@ fn coordinate_2d_to_string() {
     let coord: Coordinate2D = (1.0, -1.0, Some(4.0), Some(3.0)).into();
     assert_eq!(coord.to_string(), "(1,-1)\t+- (4,3)");
 }
+
+#[test]
+fn coordinate_2d_point_meta_to_string() {
+    let mut coord: Coordinate2D = (1.0, -1.0).into();
+    assert!(coord.point_meta.is_none());
+    coord.point_meta = Some(7.0);
+    assert_eq!(coord.to_string(), "(1,-1)\t[7]");
+
+    let mut coord: Coordinate2D = (1.0, -1.0, None, Some(3.0)).into();
+    coord.point_meta = Some(-0.5);
+    assert_eq!(coord.to_string(), "(1,-1)\t+- (0,3)\t[-0.5]");
+}
+
+#[test]
+fn coordinate_2d_parse() {
+    let coord = Coordinate2D::parse(" 1.0", "2.0").unwrap();
+    assert_eq!(coord.x, 1.0);
+    assert_eq!(coord.y, 2.0);
+    assert!(coord.error_x.is_none());
+    assert!(coord.error_y.is_none());
+
+    let coord = Coordinate2D::parse("-3,25\t", " 1e3 ").unwrap();
+    assert_eq!(coord.x, -3.25);
+    assert_eq!(coord.y, 1000.0);
+
+    assert!(Coordinate2D::parse("abc", "2.0").is_err());
+    assert!(Coordinate2D::parse("1.0", "abc").is_err());
+    assert!(Coordinate2D::parse("1.0", "").is_err());
+    assert!(Coordinate2D::parse("1,000.5", "2.0").is_err());
+}
+
+#[test]
+fn coordinate_2d_from_bounds() {
+    let coord = Coordinate2D::from_bounds(1.0, 5.0, 4.5, 8.0);
+    assert_eq!(coord.x, 1.0);
+    assert_eq!(coord.y, 5.0);
+    assert!(coord.error_x.is_none());
+    assert_eq!(coord.error_y, Some(3.0));
+    assert_eq!(coord.error_y_minus, Some(0.5));
+    assert_eq!(coord.to_string(), "(1,5)\t+= (0,3)\t-= (0,0.5)");
+
+    let mut coord = coord;
+    coord.error_x = Some(0.1);
+    assert_eq!(coord.to_string(), "(1,5)\t+= (0.1,3)\t-= (0.1,0.5)");
+}
+
+#[test]
+fn symbolic_coordinate_2d_from_tuple() {
+    let coord: SymbolicCoordinate2D = ("apples", 5.0).into();
+    assert_eq!(coord.x, String::from("apples"));
+    assert_eq!(coord.y, 5.0);
+
+    let coord: SymbolicCoordinate2D = (String::from("oranges"), -1.5).into();
+    assert_eq!(coord.x, String::from("oranges"));
+    assert_eq!(coord.y, -1.5);
+}
+
+#[test]
+fn symbolic_coordinate_2d_to_string() {
+    let coord: SymbolicCoordinate2D = ("apples", 5.0).into();
+    assert_eq!(coord.to_string(), "(apples,5)");
+}
@@ -1,4 +1,6 @@
 use super::*;
+use crate::axis::plot::color::{Color, PredefinedColor};
+use crate::dimension::Dimension;
 
 #[test]
 fn error_direction_to_string() {
@@ -8,6 +10,17 @@ fn error_direction_to_string() {
     assert_eq!(ErrorDirection::Both.to_string(), String::from("both"));
 }
 
+#[test]
+fn scatter_src_to_string() {
+    assert_eq!(ScatterSrc::Explicit.to_string(), String::from("explicit"));
+    assert_eq!(
+        ScatterSrc::ExplicitSymbolic.to_string(),
+        String::from("explicit symbolic")
+    );
+    assert_eq!(ScatterSrc::X.to_string(), String::from("x"));
+    assert_eq!(ScatterSrc::Y.to_string(), String::from("y"));
+}
+
 #[test]
 fn error_character_to_string() {
     assert_eq!(
@@ -20,6 +33,186 @@ fn error_character_to_string() {
     );
 }
 
+// This test is here only to let us know if we added an enum variant
+// but we forgot to add unit tests for it
+//
+// If this fails, it is because you added a new variant.
+// Please do the following:
+// 1) Add a unit test for the new variant you added (see examples below).
+// 2) AFTER doing (1), add the new variant to the match.
+#[test]
+fn mark_shape_tested() {
+    let mark_shape = MarkShape::O;
+    match mark_shape {
+        MarkShape::O => (),
+        MarkShape::OFilled => (),
+        MarkShape::X => (),
+        MarkShape::Plus => (),
+        MarkShape::Asterisk => (),
+        MarkShape::Star => (),
+        MarkShape::Square => (),
+        MarkShape::SquareFilled => (),
+        MarkShape::Triangle => (),
+        MarkShape::TriangleFilled => (),
+        MarkShape::Diamond => (),
+        MarkShape::DiamondFilled => (),
+        MarkShape::Pentagon => (),
+        MarkShape::PentagonFilled => (),
+    }
+}
+
+#[test]
+fn mark_shape_to_string() {
+    assert_eq!(MarkShape::O.to_string(), String::from("o"));
+    assert_eq!(MarkShape::OFilled.to_string(), String::from("*"));
+    assert_eq!(MarkShape::X.to_string(), String::from("x"));
+    assert_eq!(MarkShape::Plus.to_string(), String::from("+"));
+    assert_eq!(MarkShape::Asterisk.to_string(), String::from("asterisk"));
+    assert_eq!(MarkShape::Star.to_string(), String::from("star"));
+    assert_eq!(MarkShape::Square.to_string(), String::from("square"));
+    assert_eq!(MarkShape::SquareFilled.to_string(), String::from("square*"));
+    assert_eq!(MarkShape::Triangle.to_string(), String::from("triangle"));
+    assert_eq!(
+        MarkShape::TriangleFilled.to_string(),
+        String::from("triangle*")
+    );
+    assert_eq!(MarkShape::Diamond.to_string(), String::from("diamond"));
+    assert_eq!(
+        MarkShape::DiamondFilled.to_string(),
+        String::from("diamond*")
+    );
+    assert_eq!(MarkShape::Pentagon.to_string(), String::from("pentagon"));
+    assert_eq!(
+        MarkShape::PentagonFilled.to_string(),
+        String::from("pentagon*")
+    );
+}
+
+// This test is here only to let us know if we added an enum variant
+// but we forgot to add unit tests for it
+//
+// If this fails, it is because you added a new variant.
+// Please do the following:
+// 1) Add a unit test for the new variant you added (see examples below).
+// 2) AFTER doing (1), add the new variant to the match.
+#[test]
+fn mark_options_tested() {
+    let mark_option = MarkOption::Scale(1.0);
+    match mark_option {
+        MarkOption::Scale(_) => (),
+        MarkOption::Fill(_) => (),
+        MarkOption::FillOpacity(_) => (),
+        MarkOption::Opacity(_) => (),
+    }
+}
+
+#[test]
+fn mark_option_scale_to_string() {
+    assert_eq!(
+        MarkOption::Scale(1.5).to_string(),
+        String::from("scale=1.5")
+    );
+}
+
+#[test]
+fn mark_option_fill_to_string() {
+    assert_eq!(
+        MarkOption::Fill(PredefinedColor::Red.into()).to_string(),
+        String::from("fill=red")
+    );
+}
+
+#[test]
+fn mark_option_fill_opacity_to_string() {
+    assert_eq!(
+        MarkOption::FillOpacity(0.3).to_string(),
+        String::from("fill opacity=0.3")
+    );
+}
+
+#[test]
+fn mark_option_opacity_to_string() {
+    assert_eq!(
+        MarkOption::Opacity(0.3).to_string(),
+        String::from("opacity=0.3")
+    );
+}
+
+#[test]
+fn marker_to_string() {
+    assert_eq!(
+        Marker::new(MarkShape::O, Vec::new()).to_string(),
+        String::from("mark=o, mark options={}")
+    );
+    assert_eq!(
+        Marker::new(
+            MarkShape::OFilled,
+            vec![
+                MarkOption::Fill(PredefinedColor::Blue.into()),
+                MarkOption::FillOpacity(0.3)
+            ]
+        )
+        .to_string(),
+        String::from("mark=*, mark options={fill=blue, fill opacity=0.3}")
+    );
+    assert_eq!(
+        Marker::new(
+            MarkShape::OFilled,
+            vec![
+                MarkOption::Fill(PredefinedColor::Blue.into()),
+                MarkOption::Opacity(0.5)
+            ]
+        )
+        .to_string(),
+        String::from("mark=*, mark options={fill=blue, opacity=0.5}")
+    );
+}
+
+#[test]
+fn marker_default() {
+    assert_eq!(MarkShape::default().to_string(), String::from("o"));
+    assert_eq!(
+        Marker::default().to_string(),
+        String::from("mark=o, mark options={}")
+    );
+}
+
+#[test]
+fn marker_validate() {
+    let fill = vec![MarkOption::Fill(PredefinedColor::Red.into())];
+
+    let warning = Marker::new(MarkShape::O, fill.clone())
+        .validate()
+        .unwrap_err();
+    assert!(matches!(
+        warning,
+        MarkerWarning::FillOnOpenShape {
+            shape: MarkShape::O,
+            filled: Some(MarkShape::OFilled)
+        }
+    ));
+    let warning = Marker::new(MarkShape::X, fill.clone())
+        .validate()
+        .unwrap_err();
+    assert!(matches!(
+        warning,
+        MarkerWarning::FillOnOpenShape {
+            shape: MarkShape::X,
+            filled: None
+        }
+    ));
+
+    assert!(Marker::new(MarkShape::OFilled, fill.clone())
+        .validate()
+        .is_ok());
+    assert!(Marker::new(MarkShape::SquareFilled, fill)
+        .validate()
+        .is_ok());
+    assert!(Marker::new(MarkShape::O, vec![MarkOption::Scale(2.0)])
+        .validate()
+        .is_ok());
+}
+
 // This test is here only to let us know if we added an enum variant
 // but we forgot to add unit tests for it
 //
@@ -50,6 +243,7 @@ fn plot_type2d_tested() {
         Type2D::XComb => (),
         Type2D::YComb => (),
         Type2D::OnlyMarks => (),
+        Type2D::YBarInterval => (),
     }
 }
 
@@ -113,6 +307,10 @@ fn type_2d_to_string() {
     assert_eq!(Type2D::XComb.to_string(), String::from("xcomb"));
     assert_eq!(Type2D::YComb.to_string(), String::from("ycomb"));
     assert_eq!(Type2D::OnlyMarks.to_string(), String::from("only marks"));
+    assert_eq!(
+        Type2D::YBarInterval.to_string(),
+        String::from("ybar interval")
+    );
 }
 
 // This test is here only to let us know if we added an enum variant
@@ -132,9 +330,174 @@ fn plot_keys_tested() {
         PlotKey::XErrorDirection(_) => (),
         PlotKey::YError(_) => (),
         PlotKey::YErrorDirection(_) => (),
+        PlotKey::Marker(_) => (),
+        PlotKey::OnlyMarks => (),
+        PlotKey::Fill(_) => (),
+        PlotKey::Surf => (),
+        PlotKey::Mesh => (),
+        PlotKey::ConnectGaps(_) => (),
+        PlotKey::NodesNearCoordsStyle(_) => (),
+        PlotKey::Scatter(_) => (),
+        PlotKey::ScatterClasses(_) => (),
+        PlotKey::MarkSize(_) => (),
+        PlotKey::MatrixPlot { .. } => (),
+        PlotKey::NamePath(_) => (),
     }
 }
 
+#[test]
+fn plot_key_name_path_to_string() {
+    assert_eq!(
+        PlotKey::NamePath(String::from("upper")).to_string(),
+        String::from("name path=upper")
+    );
+}
+
+#[test]
+fn plot_key_matrix_plot_to_string() {
+    assert_eq!(
+        PlotKey::MatrixPlot { cols: 3 }.to_string(),
+        String::from("matrix plot, mesh/cols=3")
+    );
+
+    let mut plot = Plot2D::hist2d(&[(0.0, 0.0), (1.0, 1.0)], 3, 2);
+    plot.add_key(PlotKey::MatrixPlot { cols: 2 });
+    plot.add_key(PlotKey::MatrixPlot { cols: 3 });
+    assert_eq!(plot.keys.len(), 2);
+    assert_eq!(
+        plot.keys[0].to_string(),
+        String::from("scatter, scatter src=explicit")
+    );
+    assert_eq!(
+        plot.keys[1].to_string(),
+        String::from("matrix plot, mesh/cols=3")
+    );
+}
+
+#[test]
+fn plot_key_mark_size_to_string() {
+    assert_eq!(
+        PlotKey::MarkSize(Dimension::Pt(1.0)).to_string(),
+        String::from("mark size=1pt")
+    );
+
+    let mut plot = Plot2D::new();
+    plot.add_key(PlotKey::MarkSize(Dimension::Pt(1.0)));
+    plot.add_key(PlotKey::Marker(Marker::new(
+        MarkShape::O,
+        vec![MarkOption::Scale(2.0)],
+    )));
+    plot.add_key(PlotKey::MarkSize(Dimension::Pt(2.0)));
+    assert_eq!(plot.keys.len(), 2);
+    assert_eq!(
+        plot.keys[0].to_string(),
+        String::from("mark=o, mark options={scale=2}")
+    );
+    assert_eq!(plot.keys[1].to_string(), String::from("mark size=2pt"));
+}
+
+#[test]
+fn plot_key_scatter_classes_to_string() {
+    assert_eq!(
+        PlotKey::ScatterClasses(vec![
+            (
+                String::from("a"),
+                vec![String::from("mark=o"), String::from("red")]
+            ),
+            (
+                String::from("b"),
+                vec![String::from("mark=square"), String::from("blue")]
+            ),
+        ])
+        .to_string(),
+        String::from("scatter/classes={a={mark=o,red}, b={mark=square,blue}}")
+    );
+}
+
+#[test]
+fn plot_key_scatter_to_string() {
+    assert_eq!(
+        PlotKey::Scatter(ScatterSrc::Explicit).to_string(),
+        String::from("scatter, scatter src=explicit")
+    );
+    assert_eq!(
+        PlotKey::Scatter(ScatterSrc::ExplicitSymbolic).to_string(),
+        String::from("scatter, scatter src=explicit symbolic")
+    );
+    assert_eq!(
+        PlotKey::Scatter(ScatterSrc::X).to_string(),
+        String::from("scatter, scatter src=x")
+    );
+    assert_eq!(
+        PlotKey::Scatter(ScatterSrc::Y).to_string(),
+        String::from("scatter, scatter src=y")
+    );
+}
+
+#[test]
+fn plot_key_nodes_near_coords_style_to_string() {
+    assert_eq!(
+        PlotKey::NodesNearCoordsStyle(vec![String::from("rotate=90"), String::from("anchor=west")])
+            .to_string(),
+        String::from("nodes near coords style={rotate=90, anchor=west}")
+    );
+}
+
+#[test]
+fn plot_key_connect_gaps_to_string() {
+    assert_eq!(
+        PlotKey::ConnectGaps(true).to_string(),
+        String::from("unbounded coords=discard")
+    );
+    assert_eq!(
+        PlotKey::ConnectGaps(false).to_string(),
+        String::from("unbounded coords=jump")
+    );
+}
+
+#[test]
+fn plot_key_surf_to_string() {
+    assert_eq!(PlotKey::Surf.to_string(), String::from("surf"));
+}
+
+#[test]
+fn plot_key_mesh_to_string() {
+    assert_eq!(PlotKey::Mesh.to_string(), String::from("mesh"));
+}
+
+#[test]
+fn plot_key_fill_to_string() {
+    assert_eq!(
+        PlotKey::Fill(PredefinedColor::Red.into()).to_string(),
+        String::from("fill=red")
+    );
+    assert_eq!(
+        PlotKey::Fill(Color::from_hex(0x4682B4)).to_string(),
+        String::from("fill=rgb4682B4")
+    );
+}
+
+#[test]
+fn plot_key_only_marks_to_string() {
+    assert_eq!(PlotKey::OnlyMarks.to_string(), String::from("only marks"));
+}
+
+#[test]
+fn plot_key_marker_to_string() {
+    assert_eq!(
+        PlotKey::Marker(Marker::new(MarkShape::O, Vec::new())).to_string(),
+        String::from("mark=o, mark options={}")
+    );
+    assert_eq!(
+        PlotKey::Marker(Marker::new(
+            MarkShape::SquareFilled,
+            vec![MarkOption::Scale(2.0)]
+        ))
+        .to_string(),
+        String::from("mark=square*, mark options={scale=2}")
+    );
+}
+
 #[test]
 fn plot_key_custom_to_string() {
     assert_eq!(
@@ -185,6 +548,10 @@ fn plot_key_type_2d_to_string() {
         PlotKey::Type2D(Type2D::OnlyMarks).to_string(),
         String::from("only marks")
     );
+    assert_eq!(
+        PlotKey::Type2D(Type2D::YBarInterval).to_string(),
+        String::from("ybar interval")
+    );
 }
 
 #[test]
@@ -255,7 +622,130 @@ fn plot_key_y_error_direction_to_string() {
 fn plot_2d_new() {
     let plot = Plot2D::new();
     assert!(plot.coordinates.is_empty());
+    assert!(plot.symbolic_coordinates.is_empty());
+    assert!(plot.keys.is_empty());
+    assert!(plot.prefix.is_none());
+    assert!(plot.legend.is_none());
+    assert!(!plot.closed_cycle);
+    assert!(plot.fill_between.is_none());
+}
+
+#[test]
+fn plot_2d_hist2d() {
+    let points = [
+        (0.0, 0.0),
+        (0.5, 0.5),
+        (1.5, 0.2),
+        (3.5, 1.0),
+        (4.0, 2.0),
+        (4.0, 1.9),
+        (f64::NAN, 1.0),
+        (1.0, f64::INFINITY),
+    ];
+    let plot = Plot2D::hist2d(&points, 2, 2);
+    assert_eq!(plot.keys.len(), 2);
+    assert_eq!(plot.keys[0].to_string(), String::from("only marks"));
+    assert_eq!(
+        plot.keys[1].to_string(),
+        String::from("scatter, scatter src=explicit")
+    );
+
+    let cells: Vec<(f64, f64, f64)> = plot
+        .coordinates
+        .iter()
+        .map(|c| (c.x, c.y, c.point_meta.unwrap()))
+        .collect();
+    assert_eq!(
+        cells,
+        [
+            (1.0, 0.5, 3.0),
+            (3.0, 0.5, 0.0),
+            (1.0, 1.5, 0.0),
+            (3.0, 1.5, 3.0)
+        ]
+    );
+    assert_eq!(
+        plot.coordinates[0].to_string(),
+        String::from("(1,0.5)\t[3]")
+    );
+
+    // All points in the same place
+    let plot = Plot2D::hist2d(&[(1.0, 2.0), (1.0, 2.0)], 3, 1);
+    let counts: Vec<f64> = plot
+        .coordinates
+        .iter()
+        .map(|c| c.point_meta.unwrap())
+        .collect();
+    assert_eq!(counts, [2.0, 0.0, 0.0]);
+
+    assert!(Plot2D::hist2d(&[], 2, 2).coordinates.is_empty());
+    assert!(Plot2D::hist2d(&[(1.0, 1.0)], 0, 2).coordinates.is_empty());
+}
+
+#[test]
+fn plot_2d_with_capacity() {
+    let plot = Plot2D::with_capacity(100);
+    assert!(plot.coordinates.is_empty());
+    assert!(plot.coordinates.capacity() >= 100);
+    assert!(plot.symbolic_coordinates.is_empty());
     assert!(plot.keys.is_empty());
+    assert!(plot.prefix.is_none());
+    assert!(plot.legend.is_none());
+    assert!(!plot.closed_cycle);
+    assert!(plot.fill_between.is_none());
+}
+
+#[test]
+fn plot_2d_from_xy() {
+    let plot = Plot2D::from_xy(&[1.0, 2.0, 3.0], &[-1.0, -2.0, -3.0]).unwrap();
+    assert!(plot.keys.is_empty());
+    let coordinates: Vec<(f64, f64)> = plot.coordinates.iter().map(|c| (c.x, c.y)).collect();
+    assert_eq!(coordinates, vec![(1.0, -1.0), (2.0, -2.0), (3.0, -3.0)]);
+    assert!(plot
+        .coordinates
+        .iter()
+        .all(|c| c.error_x.is_none() && c.error_y.is_none()));
+
+    let plot = Plot2D::from_xy(&[], &[]).unwrap();
+    assert!(plot.coordinates.is_empty());
+
+    let err = Plot2D::from_xy(&[1.0, 2.0, 3.0], &[1.0]).unwrap_err();
+    assert_eq!(err.expected, 3);
+    assert_eq!(err.found, 1);
+}
+
+#[test]
+fn plot_2d_from_xy_errors() {
+    let plot =
+        Plot2D::from_xy_errors(&[1.0, 2.0], &[-1.0, -2.0], &[0.1, 0.2], &[0.3, 0.4]).unwrap();
+    assert_eq!(plot.coordinates.len(), 2);
+    assert_eq!(plot.coordinates[1].x, 2.0);
+    assert_eq!(plot.coordinates[1].y, -2.0);
+    assert_eq!(plot.coordinates[1].error_x, Some(0.2));
+    assert_eq!(plot.coordinates[1].error_y, Some(0.4));
+
+    let err = Plot2D::from_xy_errors(&[1.0, 2.0], &[1.0, 2.0], &[0.1, 0.2], &[0.3]).unwrap_err();
+    assert_eq!(err.expected, 2);
+    assert_eq!(err.found, 1);
+    assert!(Plot2D::from_xy_errors(&[1.0], &[1.0, 2.0], &[0.1], &[0.3]).is_err());
+}
+
+#[test]
+fn plot_2d_into_iter() {
+    let mut plot = Plot2D::new();
+    plot.coordinates = vec![(3.0, 1.0).into(), (1.0, 2.0).into(), (2.0, 3.0).into()];
+
+    let mut x = Vec::new();
+    for coordinate in &plot {
+        x.push(coordinate.x);
+    }
+    assert_eq!(x, vec![3.0, 1.0, 2.0]);
+
+    for coordinate in &mut plot {
+        coordinate.y = -coordinate.y;
+    }
+    let y: Vec<f64> = (&plot).into_iter().map(|c| c.y).collect();
+    assert_eq!(y, vec![-1.0, -2.0, -3.0]);
 }
 
 #[test]
@@ -312,6 +802,581 @@ fn plot_2d_add_key() {
     );
 }
 
+#[test]
+fn plot_2d_enable_errors() {
+    let mut plot = Plot2D::new();
+    plot.enable_x_errors(ErrorCharacter::Absolute, ErrorDirection::Both);
+    assert_eq!(plot.keys.len(), 2);
+    assert_eq!(
+        plot.keys[0].to_string(),
+        String::from("error bars/x explicit")
+    );
+    assert_eq!(
+        plot.keys[1].to_string(),
+        String::from("error bars/x dir=both")
+    );
+
+    plot.enable_y_errors(ErrorCharacter::Relative, ErrorDirection::Plus);
+    assert_eq!(plot.keys.len(), 4);
+    assert_eq!(
+        plot.keys[2].to_string(),
+        String::from("error bars/y explicit relative")
+    );
+    assert_eq!(
+        plot.keys[3].to_string(),
+        String::from("error bars/y dir=plus")
+    );
+
+    plot.enable_x_errors(ErrorCharacter::Relative, ErrorDirection::Minus);
+    assert_eq!(plot.keys.len(), 4);
+    assert_eq!(
+        plot.keys[2].to_string(),
+        String::from("error bars/x explicit relative")
+    );
+    assert_eq!(
+        plot.keys[3].to_string(),
+        String::from("error bars/x dir=minus")
+    );
+}
+
+#[test]
+fn plot_2d_concat() {
+    let mut plot = Plot2D::new();
+    plot.add_key(PlotKey::Type2D(Type2D::SharpPlot));
+    plot.coordinates = vec![(1.0, 1.0).into(), (2.0, 2.0).into()];
+    let mut other = Plot2D::new();
+    other.add_key(PlotKey::Type2D(Type2D::OnlyMarks));
+    other.add_key(PlotKey::XError(ErrorCharacter::Absolute));
+    other.coordinates = vec![(3.0, 3.0).into(), (0.0, 0.0).into()];
+
+    plot.concat(other);
+    let x: Vec<f64> = plot.coordinates.iter().map(|c| c.x).collect();
+    assert_eq!(x, vec![1.0, 2.0, 3.0, 0.0]);
+    assert_eq!(plot.keys.len(), 1);
+    assert_eq!(plot.keys[0].to_string(), String::from("sharp plot"));
+}
+
+#[test]
+fn plot_2d_concatenated() {
+    let mut first = Plot2D::new();
+    first.add_key(PlotKey::Type2D(Type2D::SharpPlot));
+    first.coordinates = vec![(1.0, 1.0).into()];
+    let mut second = Plot2D::new();
+    second.add_key(PlotKey::Type2D(Type2D::OnlyMarks));
+    second.coordinates = vec![(2.0, 2.0).into(), (3.0, 3.0).into()];
+
+    let plot = first.concatenated(second).concatenated(Plot2D::new());
+    let x: Vec<f64> = plot.coordinates.iter().map(|c| c.x).collect();
+    assert_eq!(x, vec![1.0, 2.0, 3.0]);
+    assert_eq!(plot.keys.len(), 1);
+    assert_eq!(plot.keys[0].to_string(), String::from("sharp plot"));
+}
+
+#[test]
+fn plot_2d_reverse() {
+    let mut plot = Plot2D::new();
+    plot.add_key(PlotKey::Type2D(Type2D::SharpPlot));
+    plot.coordinates = vec![(1.0, 3.0).into(), (2.0, 1.0).into(), (3.0, 2.0).into()];
+
+    plot.reverse();
+    let x: Vec<f64> = plot.coordinates.iter().map(|c| c.x).collect();
+    assert_eq!(x, vec![3.0, 2.0, 1.0]);
+    let y: Vec<f64> = plot.coordinates.iter().map(|c| c.y).collect();
+    assert_eq!(y, vec![2.0, 1.0, 3.0]);
+    assert_eq!(plot.keys.len(), 1);
+}
+
+#[test]
+fn plot_2d_dedup_consecutive() {
+    let mut plot = Plot2D::new();
+    plot.coordinates = vec![
+        (0.0, 1.0, None, Some(0.1)).into(),
+        (0.0, 1.0, None, Some(0.2)).into(),
+        (0.0, 1.0).into(),
+        (1.0, 1.0).into(),
+        (1.0, 2.0).into(),
+        (1.0, 2.0).into(),
+        (0.0, 1.0).into(),
+        (f64::NAN, 3.0).into(),
+        (f64::NAN, 3.0).into(),
+    ];
+
+    plot.dedup_consecutive();
+    let coordinates: Vec<(f64, f64)> = plot.coordinates.iter().map(|c| (c.x, c.y)).collect();
+    assert_eq!(coordinates.len(), 5);
+    assert_eq!(
+        coordinates[..4],
+        [(0.0, 1.0), (1.0, 1.0), (1.0, 2.0), (0.0, 1.0)]
+    );
+    assert!(coordinates[4].0.is_nan());
+    assert_eq!(plot.coordinates[0].error_y, Some(0.1));
+}
+
+#[test]
+fn plot_2d_fluent_errors() {
+    let plot = Plot2D::new()
+        .with_error_coordinates(vec![(1.0, 2.0, 0.1, 0.2), (3.0, 4.0, 0.3, 0.4)])
+        .with_x_errors(ErrorCharacter::Absolute, ErrorDirection::Both)
+        .with_y_errors(ErrorCharacter::Relative, ErrorDirection::Plus)
+        .with_error_coordinates([(5.0, 6.0, 0.5, 0.6)]);
+
+    assert_eq!(plot.keys.len(), 4);
+    assert_eq!(
+        plot.keys[0].to_string(),
+        String::from("error bars/x explicit")
+    );
+    assert_eq!(
+        plot.keys[1].to_string(),
+        String::from("error bars/x dir=both")
+    );
+    assert_eq!(
+        plot.keys[2].to_string(),
+        String::from("error bars/y explicit relative")
+    );
+    assert_eq!(
+        plot.keys[3].to_string(),
+        String::from("error bars/y dir=plus")
+    );
+
+    let coordinates: Vec<String> = plot.coordinates.iter().map(|c| c.to_string()).collect();
+    assert_eq!(
+        coordinates,
+        [
+            "(1,2)\t+- (0.1,0.2)",
+            "(3,4)\t+- (0.3,0.4)",
+            "(5,6)\t+- (0.5,0.6)"
+        ]
+    );
+}
+
+#[test]
+fn plot_2d_map_x() {
+    let mut plot =
+        Plot2D::from_xy_errors(&[1.0, 2.0], &[3.0, 4.0], &[0.1, 0.2], &[0.3, 0.4]).unwrap();
+    plot.map_x(|x| -x);
+    let coordinates: Vec<(f64, f64)> = plot.coordinates.iter().map(|c| (c.x, c.y)).collect();
+    assert_eq!(coordinates, [(-1.0, 3.0), (-2.0, 4.0)]);
+    assert_eq!(plot.coordinates[1].error_x, Some(0.2));
+}
+
+#[test]
+fn plot_2d_map_y() {
+    let mut plot = Plot2D::from_xy_errors(
+        &[1.0, 2.0, 3.0],
+        &[1.0, 100.0, 1000.0],
+        &[0.0, 0.0, 0.0],
+        &[0.5, 5.0, 50.0],
+    )
+    .unwrap();
+    plot.symbolic_coordinates = vec![("a", 10.0).into()];
+    plot.map_y(|y| y.log10());
+
+    let values: Vec<f64> = plot.coordinates.iter().map(|c| c.y).collect();
+    assert_eq!(values, [0.0, 2.0, 3.0]);
+    assert_eq!(plot.symbolic_coordinates[0].y, 1.0);
+    // Errors are not transformed
+    let errors: Vec<Option<f64>> = plot.coordinates.iter().map(|c| c.error_y).collect();
+    assert_eq!(errors, [Some(0.5), Some(5.0), Some(50.0)]);
+}
+
+#[test]
+fn plot_2d_retain() {
+    let mut plot = Plot2D::from_xy(&[0.0, 1.0, 2.0, 3.0], &[1.0, 50.0, 2.0, -40.0]).unwrap();
+    plot.retain(|c| c.y.abs() < 10.0);
+    let coordinates: Vec<(f64, f64)> = plot.coordinates.iter().map(|c| (c.x, c.y)).collect();
+    assert_eq!(coordinates, [(0.0, 1.0), (2.0, 2.0)]);
+
+    let plot = Plot2D::from_xy(&[0.0, 1.0, 2.0, 3.0], &[1.0, 50.0, 2.0, -40.0])
+        .unwrap()
+        .with_legend("data")
+        .filtered(|c| c.x > 0.5);
+    let coordinates: Vec<(f64, f64)> = plot.coordinates.iter().map(|c| (c.x, c.y)).collect();
+    assert_eq!(coordinates, [(1.0, 50.0), (2.0, 2.0), (3.0, -40.0)]);
+    assert!(plot.to_string().contains("\\addlegendentry{data}"));
+}
+
+#[test]
+fn plot_2d_filter_nonpositive_for_log() {
+    let mut plot = Plot2D::new();
+    plot.coordinates = vec![
+        (1.0, 2.0).into(),
+        (2.0, 0.0).into(),
+        (3.0, -0.0).into(),
+        (4.0, -5.0).into(),
+        (-5.0, 1e-9).into(),
+        (6.0, f64::NAN).into(),
+    ];
+
+    assert_eq!(plot.filter_nonpositive_for_log(), 3);
+    let x: Vec<f64> = plot.coordinates.iter().map(|c| c.x).collect();
+    assert_eq!(x, vec![1.0, -5.0, 6.0]);
+
+    assert_eq!(plot.filter_nonpositive_for_log(), 0);
+    assert_eq!(plot.coordinates.len(), 3);
+}
+
+#[test]
+fn plot_2d_set_closed_cycle() {
+    let mut plot = Plot2D::from_xy(&[0.0, 1.0], &[1.0, 2.0]).unwrap();
+    plot.add_key(PlotKey::Fill(PredefinedColor::Blue.into()));
+    plot.set_closed_cycle(true);
+    assert_eq!(
+        plot.to_string(),
+        "\t\\addplot[\n\t\tfill=blue,\n\t] coordinates {\n\t\t(0,1)\n\t\t(1,2)\n\t} \\closedcycle;"
+    );
+
+    plot.set_closed_cycle(false);
+    assert!(plot.to_string().ends_with("\n\t};"));
+}
+
+#[test]
+fn plot_2d_clear() {
+    let mut plot = Plot2D::new();
+    plot.add_key(PlotKey::Type2D(Type2D::SharpPlot));
+    plot.coordinates = vec![(1.0, 1.0).into(), (2.0, 2.0).into()];
+    plot.symbolic_coordinates = vec![("apples", 5.0).into()];
+    plot.set_prefix(String::from("% comment"));
+    plot.set_legend("data");
+    plot.set_closed_cycle(true);
+    plot.fill_between = Some((String::from("a"), String::from("b")));
+
+    plot.clear();
+    assert!(plot.keys.is_empty());
+    assert!(plot.prefix.is_none());
+    assert!(plot.legend.is_none());
+    assert!(!plot.closed_cycle);
+    assert!(plot.fill_between.is_none());
+    assert!(plot.coordinates.is_empty());
+    assert!(plot.symbolic_coordinates.is_empty());
+}
+
+#[test]
+fn plot_2d_band_between() {
+    let mut upper = Plot2D::new();
+    upper.add_key(PlotKey::Type2D(Type2D::Smooth { tension: 0.55 }));
+    upper.coordinates = vec![(0.0, 2.0).into(), (1.0, 3.0).into()];
+    let mut lower = Plot2D::new();
+    lower.coordinates = vec![(0.0, 1.0).into(), (1.0, 1.5).into()];
+
+    let plots = Plot2D::band_between(&upper, &lower, PredefinedColor::Gray.into());
+    assert_eq!(plots.len(), 3);
+
+    assert_eq!(plots[0].keys.len(), 2);
+    assert_eq!(
+        plots[0].keys[0].to_string(),
+        String::from("smooth, tension=0.55")
+    );
+    assert_eq!(
+        plots[0].keys[1].to_string(),
+        String::from("name path=upper")
+    );
+    let y: Vec<f64> = plots[0].coordinates.iter().map(|c| c.y).collect();
+    assert_eq!(y, vec![2.0, 3.0]);
+
+    assert_eq!(plots[1].keys.len(), 1);
+    assert_eq!(
+        plots[1].keys[0].to_string(),
+        String::from("name path=lower")
+    );
+    let y: Vec<f64> = plots[1].coordinates.iter().map(|c| c.y).collect();
+    assert_eq!(y, vec![1.0, 1.5]);
+
+    assert_eq!(plots[2].keys.len(), 1);
+    assert_eq!(plots[2].keys[0].to_string(), String::from("fill=gray"));
+    assert!(plots[2].coordinates.is_empty());
+    assert!(plots[2].requires_fillbetween());
+    assert_eq!(
+        plots[2].to_string(),
+        String::from("\t\\addplot[\n\t\tfill=gray,\n\t] fill between[of=upper and lower];")
+    );
+
+    // Existing name paths are kept
+    let upper = upper.with_name_path("max");
+    let plots = Plot2D::band_between(&upper, &lower, PredefinedColor::Gray.into());
+    assert_eq!(plots[0].keys.len(), 2);
+    assert_eq!(plots[0].keys[1].to_string(), String::from("name path=max"));
+    assert!(plots[2]
+        .to_string()
+        .ends_with("fill between[of=max and lower];"));
+}
+
+#[test]
+fn plot_2d_with_name_path() {
+    let plot = Plot2D::new().with_name_path("a").with_name_path("b");
+    assert_eq!(plot.keys.len(), 1);
+    assert_eq!(plot.keys[0].to_string(), String::from("name path=b"));
+    assert_eq!(plot.name_path(), Some("b"));
+    assert!(Plot2D::new().name_path().is_none());
+}
+
+#[test]
+fn plot_2d_set_errors() {
+    let mut plot = Plot2D::new();
+    plot.coordinates = vec![
+        (1.0, 4.0, Some(1.0), None).into(),
+        (2.0, 16.0).into(),
+        (3.0, 25.0).into(),
+    ];
+    plot.set_errors(|_| None, |c| Some(c.y.sqrt()));
+
+    assert!(plot.coordinates.iter().all(|c| c.error_x.is_none()));
+    let errors: Vec<f64> = plot
+        .coordinates
+        .iter()
+        .map(|c| c.error_y.unwrap())
+        .collect();
+    assert_eq!(errors, vec![2.0, 4.0, 5.0]);
+
+    plot.set_errors(|c| Some(c.x / 10.0), |_| None);
+    assert_eq!(plot.coordinates[0].error_x, Some(0.1));
+    assert!(plot.coordinates.iter().all(|c| c.error_y.is_none()));
+}
+
+#[test]
+fn plot_2d_moving_average() {
+    let mut plot = Plot2D::new();
+    plot.add_key(PlotKey::Type2D(Type2D::SharpPlot));
+    plot.coordinates = vec![
+        (1.0, 1.0).into(),
+        (2.0, 2.0).into(),
+        (3.0, 3.0).into(),
+        (4.0, 4.0).into(),
+        (5.0, 5.0).into(),
+    ];
+
+    let smooth = plot.moving_average(3);
+    let x: Vec<f64> = smooth.coordinates.iter().map(|c| c.x).collect();
+    let y: Vec<f64> = smooth.coordinates.iter().map(|c| c.y).collect();
+    assert_eq!(x, vec![1.0, 2.0, 3.0, 4.0, 5.0]);
+    assert_eq!(y, vec![1.5, 2.0, 3.0, 4.0, 4.5]);
+    assert_eq!(smooth.keys.len(), 1);
+    assert_eq!(smooth.keys[0].to_string(), String::from("sharp plot"));
+
+    let smooth = plot.moving_average(4);
+    let y: Vec<f64> = smooth.coordinates.iter().map(|c| c.y).collect();
+    assert_eq!(y, vec![2.0, 2.5, 3.5, 4.0, 4.5]);
+
+    let smooth = plot.moving_average(1);
+    let y: Vec<f64> = smooth.coordinates.iter().map(|c| c.y).collect();
+    assert_eq!(y, vec![1.0, 2.0, 3.0, 4.0, 5.0]);
+
+    assert!(Plot2D::new().moving_average(3).coordinates.is_empty());
+}
+
+#[test]
+fn plot_2d_cumulative() {
+    let mut plot = Plot2D::new();
+    plot.add_key(PlotKey::Type2D(Type2D::ConstRight));
+    plot.coordinates = vec![
+        (1.0, 1.0).into(),
+        (2.0, -3.0).into(),
+        (3.0, 0.5).into(),
+        (4.0, 2.0).into(),
+    ];
+
+    let cumulative = plot.cumulative();
+    let x: Vec<f64> = cumulative.coordinates.iter().map(|c| c.x).collect();
+    let y: Vec<f64> = cumulative.coordinates.iter().map(|c| c.y).collect();
+    assert_eq!(x, vec![1.0, 2.0, 3.0, 4.0]);
+    assert_eq!(y, vec![1.0, -2.0, -1.5, 0.5]);
+    assert_eq!(cumulative.keys.len(), 1);
+
+    assert!(Plot2D::new().cumulative().coordinates.is_empty());
+}
+
+#[test]
+fn plot_2d_interpolate_at() {
+    let mut plot = Plot2D::new();
+    plot.coordinates = vec![(3.0, 0.0).into(), (1.0, 2.0).into(), (2.0, 4.0).into()];
+
+    assert_eq!(plot.interpolate_at(1.0), Some(2.0));
+    assert_eq!(plot.interpolate_at(1.5), Some(3.0));
+    assert_eq!(plot.interpolate_at(2.0), Some(4.0));
+    assert_eq!(plot.interpolate_at(2.25), Some(3.0));
+    assert_eq!(plot.interpolate_at(3.0), Some(0.0));
+    assert_eq!(plot.interpolate_at(0.5), None);
+    assert_eq!(plot.interpolate_at(3.5), None);
+    assert_eq!(plot.interpolate_at(f64::NAN), None);
+
+    let mut single = Plot2D::new();
+    single.coordinates = vec![(1.0, 1.0).into()];
+    assert_eq!(single.interpolate_at(1.0), None);
+    assert_eq!(Plot2D::new().interpolate_at(0.0), None);
+}
+
+#[test]
+fn plot_2d_numeric_derivative() {
+    let mut plot = Plot2D::new();
+    plot.add_key(PlotKey::Type2D(Type2D::SharpPlot));
+    plot.coordinates = linspace(0.0, 2.0, 201)
+        .into_iter()
+        .map(|x| (x, x * x, Some(0.1), Some(0.1)).into())
+        .collect();
+
+    let derivative = plot.numeric_derivative();
+    assert_eq!(derivative.keys.len(), 1);
+    assert_eq!(derivative.coordinates.len(), 201);
+    for (coordinate, original) in derivative.coordinates.iter().zip(plot.coordinates.iter()) {
+        assert_eq!(coordinate.x, original.x);
+        assert!(coordinate.error_x.is_none());
+        assert!(coordinate.error_y.is_none());
+    }
+    // Central differences are exact for a parabola. Only the edges (forward
+    // and backward differences) are off by half the step.
+    for coordinate in &derivative.coordinates[1..200] {
+        assert!((coordinate.y - 2.0 * coordinate.x).abs() < 1e-9);
+    }
+    assert!((derivative.coordinates[0].y - 0.01).abs() < 1e-9);
+    assert!((derivative.coordinates[200].y - 3.99).abs() < 1e-9);
+
+    assert!(Plot2D::new().numeric_derivative().coordinates.is_empty());
+    let mut single = Plot2D::new();
+    single.coordinates = vec![(1.0, 1.0).into()];
+    assert!(single.numeric_derivative().coordinates[0].y.is_nan());
+}
+
+#[test]
+fn plot_2d_numeric_integral() {
+    let mut plot = Plot2D::new();
+    plot.add_key(PlotKey::Type2D(Type2D::SharpPlot));
+    plot.coordinates = linspace(0.0, 2.0, 201)
+        .into_iter()
+        .map(|x| (x, x * x, Some(0.1), Some(0.1)).into())
+        .collect();
+
+    let integral = plot.numeric_integral();
+    assert_eq!(integral.keys.len(), 1);
+    assert_eq!(integral.coordinates.len(), 201);
+    assert_eq!(integral.coordinates[0].y, 0.0);
+    for (coordinate, original) in integral.coordinates.iter().zip(plot.coordinates.iter()) {
+        assert_eq!(coordinate.x, original.x);
+        assert!(coordinate.error_x.is_none());
+        assert!(coordinate.error_y.is_none());
+        assert!((coordinate.y - coordinate.x.powi(3) / 3.0).abs() < 1e-3);
+    }
+
+    assert!(Plot2D::new().numeric_integral().coordinates.is_empty());
+}
+
+#[test]
+fn plot_2d_add_key_plot_type() {
+    let mut plot = Plot2D::new();
+    plot.add_key(PlotKey::Type2D(Type2D::SharpPlot));
+    plot.add_key(PlotKey::XError(ErrorCharacter::Absolute));
+    plot.add_key(PlotKey::OnlyMarks);
+    assert_eq!(plot.keys.len(), 2);
+    assert_eq!(
+        plot.keys[0].to_string(),
+        String::from("error bars/x explicit")
+    );
+    assert_eq!(plot.keys[1].to_string(), String::from("only marks"));
+
+    plot.add_key(PlotKey::OnlyMarks);
+    assert_eq!(plot.keys.len(), 2);
+    assert_eq!(plot.keys[1].to_string(), String::from("only marks"));
+
+    plot.add_key(PlotKey::Type2D(Type2D::ConstLeft));
+    assert_eq!(plot.keys.len(), 2);
+    assert_eq!(
+        plot.keys[0].to_string(),
+        String::from("error bars/x explicit")
+    );
+    assert_eq!(
+        plot.keys[1].to_string(),
+        String::from("const plot mark left")
+    );
+
+    plot.add_key(PlotKey::Surf);
+    assert_eq!(plot.keys.len(), 2);
+    assert_eq!(plot.keys[1].to_string(), String::from("surf"));
+
+    plot.add_key(PlotKey::Mesh);
+    assert_eq!(plot.keys.len(), 2);
+    assert_eq!(plot.keys[1].to_string(), String::from("mesh"));
+
+    plot.add_key(PlotKey::MatrixPlot { cols: 4 });
+    assert_eq!(plot.keys.len(), 2);
+    assert_eq!(
+        plot.keys[1].to_string(),
+        String::from("matrix plot, mesh/cols=4")
+    );
+
+    plot.add_key(PlotKey::Type2D(Type2D::SharpPlot));
+    assert_eq!(plot.keys.len(), 2);
+    assert_eq!(plot.keys[1].to_string(), String::from("sharp plot"));
+}
+
+#[test]
+fn plot_2d_y_bar_interval_to_string() {
+    // Two bins: [0, 1) with 3 counts, and [1, 2) with 5 counts. The last
+    // coordinate only sets the right edge of the last bin.
+    let mut plot = Plot2D::new();
+    plot.add_key(PlotKey::Type2D(Type2D::YBarInterval));
+    plot.coordinates = vec![(0.0, 3.0).into(), (1.0, 5.0).into(), (2.0, 5.0).into()];
+    assert_eq!(
+        plot.to_string(),
+        "\t\\addplot[\n\t\tybar interval,\n\t] coordinates {\n\t\t(0,3)\n\t\t(1,5)\n\t\t(2,5)\n\t};"
+    );
+}
+
+#[test]
+fn plot_2d_symbolic_coordinates_to_string() {
+    let mut plot = Plot2D::new();
+    plot.symbolic_coordinates = vec![("apples", 5.0).into(), ("oranges", 3.0).into()];
+    assert_eq!(
+        plot.to_string(),
+        "\t\\addplot[] coordinates {\n\t\t(apples,5)\n\t\t(oranges,3)\n\t};"
+    );
+}
+
+#[test]
+fn plot_2d_clone_with_keys() {
+    let mut plot = Plot2D::new();
+    plot.add_key(PlotKey::Type2D(Type2D::SharpPlot));
+    plot.add_key(PlotKey::XError(ErrorCharacter::Absolute));
+    plot.coordinates = vec![(1.0, 2.0, Some(0.1), None).into(), (3.0, 4.0).into()];
+
+    let other = plot.clone_with_keys([
+        PlotKey::Custom(String::from("dashed")),
+        PlotKey::Type2D(Type2D::ConstLeft),
+        PlotKey::OnlyMarks,
+    ]);
+    assert_eq!(other.keys.len(), 2);
+    assert_eq!(other.keys[0].to_string(), String::from("dashed"));
+    assert_eq!(other.keys[1].to_string(), String::from("only marks"));
+    let coordinates: Vec<(f64, f64, Option<f64>)> = other
+        .coordinates
+        .iter()
+        .map(|c| (c.x, c.y, c.error_x))
+        .collect();
+    assert_eq!(coordinates, vec![(1.0, 2.0, Some(0.1)), (3.0, 4.0, None)]);
+
+    assert_eq!(plot.keys.len(), 2);
+    assert!(plot.clone_with_keys([]).keys.is_empty());
+}
+
+#[test]
+fn plot_2d_with_legend() {
+    let plot = Plot2D::new().with_legend("first").with_legend("model");
+    assert_eq!(plot.legend, Some(String::from("model")));
+    assert_eq!(
+        plot.to_string(),
+        "\t\\addplot[] coordinates {\n\t};\n\t\\addlegendentry{model}"
+    );
+}
+
+#[test]
+fn plot_2d_prefix_to_string() {
+    let mut plot = Plot2D::new();
+    plot.set_prefix(String::from("% first"));
+    plot.set_prefix(String::from("\\pgfplotsset{cycle list shift=1}"));
+    plot.coordinates.push((1.0, -1.0).into());
+    assert_eq!(
+        plot.to_string(),
+        "\t\\pgfplotsset{cycle list shift=1}\n\t\\addplot[] coordinates {\n\t\t(1,-1)\n\t};"
+    );
+}
+
 #[test]
 fn plot_2d_to_string() {
     let mut plot = Plot2D::new();
@@ -340,3 +1405,28 @@ fn plot_2d_to_string() {
         "\t\\addplot[\n\t\tsharp plot,\n\t\terror bars/x explicit,\n\t\terror bars/x dir=both,\n\t] coordinates {\n\t\t(1,-1)\n\t\t(2,-2)\n\t\t(3,-3)\n\t};"
     );
 }
+
+#[test]
+fn linspace_values() {
+    assert!(linspace(0.0, 1.0, 0).is_empty());
+    assert_eq!(linspace(2.0, 5.0, 1), vec![2.0]);
+    assert_eq!(linspace(-1.0, 1.0, 5), vec![-1.0, -0.5, 0.0, 0.5, 1.0]);
+    assert_eq!(linspace(1.0, 0.0, 3), vec![1.0, 0.5, 0.0]);
+
+    let values = linspace(0.0, 0.3, 7);
+    assert_eq!(values.len(), 7);
+    assert_eq!(values[0], 0.0);
+    assert_eq!(values[6], 0.3);
+}
+
+#[test]
+fn logspace_values() {
+    assert!(logspace(0.0, 1.0, 0).is_empty());
+    assert_eq!(logspace(2.0, 5.0, 1), vec![100.0]);
+
+    let values = logspace(-1.0, 3.0, 5);
+    assert_eq!(values.len(), 5);
+    for (value, expected) in values.iter().zip([0.1, 1.0, 10.0, 100.0, 1000.0]) {
+        assert!((value - expected).abs() < 1e-12 * expected);
+    }
+}
@@ -1,5 +1,5 @@
 use super::*;
-use crate::axis::plot::{PlotKey, *};
+use crate::axis::plot::{color::PredefinedColor, PlotKey, *};
 
 #[test]
 fn scale_to_string() {
@@ -7,6 +7,94 @@ fn scale_to_string() {
     assert_eq!(Scale::Normal.to_string(), String::from("normal"));
 }
 
+#[test]
+fn font_size_to_string() {
+    assert_eq!(FontSize::Tiny.to_string(), String::from("\\tiny"));
+    assert_eq!(
+        FontSize::ScriptSize.to_string(),
+        String::from("\\scriptsize")
+    );
+    assert_eq!(
+        FontSize::FootnoteSize.to_string(),
+        String::from("\\footnotesize")
+    );
+    assert_eq!(FontSize::Small.to_string(), String::from("\\small"));
+    assert_eq!(
+        FontSize::NormalSize.to_string(),
+        String::from("\\normalsize")
+    );
+    assert_eq!(FontSize::LowerLarge.to_string(), String::from("\\large"));
+    assert_eq!(FontSize::Large.to_string(), String::from("\\Large"));
+    assert_eq!(FontSize::UpperLarge.to_string(), String::from("\\LARGE"));
+    assert_eq!(FontSize::LowerHuge.to_string(), String::from("\\huge"));
+    assert_eq!(FontSize::Huge.to_string(), String::from("\\Huge"));
+}
+
+#[test]
+fn corner_to_string() {
+    assert_eq!(Corner::NorthWest.to_string(), String::from("north west"));
+    assert_eq!(Corner::NorthEast.to_string(), String::from("north east"));
+    assert_eq!(Corner::SouthWest.to_string(), String::from("south west"));
+    assert_eq!(Corner::SouthEast.to_string(), String::from("south east"));
+}
+
+#[test]
+fn colorbar_pos_to_string() {
+    assert_eq!(
+        ColorbarPos::Right.to_string(),
+        String::from("colorbar right")
+    );
+    assert_eq!(ColorbarPos::Left.to_string(), String::from("colorbar left"));
+    assert_eq!(
+        ColorbarPos::Top.to_string(),
+        String::from("colorbar horizontal")
+    );
+    assert_eq!(
+        ColorbarPos::Bottom.to_string(),
+        String::from("colorbar horizontal")
+    );
+}
+
+#[test]
+fn unbounded_coords_to_string() {
+    assert_eq!(
+        UnboundedCoords::Discard.to_string(),
+        String::from("discard")
+    );
+    assert_eq!(UnboundedCoords::Jump.to_string(), String::from("jump"));
+}
+
+#[test]
+fn ticks_to_string() {
+    assert_eq!(Ticks::Data.to_string(), String::from("data"));
+    assert_eq!(Ticks::Values(Vec::new()).to_string(), String::from("{}"));
+    assert_eq!(
+        Ticks::Values(vec![-1.0, 2.5]).to_string(),
+        String::from("{-1, 2.5}")
+    );
+}
+
+#[test]
+fn axis_dir_to_string() {
+    assert_eq!(AxisDir::Normal.to_string(), String::from("normal"));
+    assert_eq!(AxisDir::Reverse.to_string(), String::from("reverse"));
+}
+
+#[test]
+fn grid_to_string() {
+    assert_eq!(Grid::None.to_string(), String::from("none"));
+    assert_eq!(Grid::Major.to_string(), String::from("major"));
+    assert_eq!(Grid::Minor.to_string(), String::from("minor"));
+    assert_eq!(Grid::Both.to_string(), String::from("both"));
+}
+
+#[test]
+fn align_to_string() {
+    assert_eq!(Align::Left.to_string(), String::from("left"));
+    assert_eq!(Align::Right.to_string(), String::from("right"));
+    assert_eq!(Align::Center.to_string(), String::from("center"));
+}
+
 // This test is here only to let us know if we added an enum variant
 // but we forgot to add unit tests for it
 //
@@ -15,16 +103,749 @@ fn scale_to_string() {
 // 1) Add a unit test for the new variant you added (see examples below).
 // 2) AFTER doing (1), add the new variant to the match.
 #[test]
-fn axis_keys_tested() {
-    let axis_key = AxisKey::Custom(String::from(""));
-    match axis_key {
-        AxisKey::Custom(_) => (),
-        AxisKey::XMode(_) => (),
-        AxisKey::YMode(_) => (),
-        AxisKey::Title(_) => (),
-        AxisKey::XLabel(_) => (),
-        AxisKey::YLabel(_) => (),
-    }
+fn axis_keys_tested() {
+    let axis_key = AxisKey::Custom(String::from(""));
+    match axis_key {
+        AxisKey::Custom(_) => (),
+        AxisKey::XMode(_) => (),
+        AxisKey::YMode(_) => (),
+        AxisKey::Title(_) => (),
+        AxisKey::XLabel(_) => (),
+        AxisKey::YLabel(_) => (),
+        AxisKey::ExtraXTicks(_) => (),
+        AxisKey::ExtraYTicks(_) => (),
+        AxisKey::ExtraXTickStyle(_) => (),
+        AxisKey::YTickLabelStyle(_) => (),
+        AxisKey::XDir(_) => (),
+        AxisKey::YDir(_) => (),
+        AxisKey::LegendColumns(_) => (),
+        AxisKey::TitleStyle(_) => (),
+        AxisKey::DefineColormap(_) => (),
+        AxisKey::View(_, _) => (),
+        AxisKey::XMin(_) => (),
+        AxisKey::XMax(_) => (),
+        AxisKey::YMin(_) => (),
+        AxisKey::YMax(_) => (),
+        AxisKey::Width(_) => (),
+        AxisKey::Height(_) => (),
+        AxisKey::ScaleOnlyAxis(_) => (),
+        AxisKey::ClipMarkerPaths(_) => (),
+        AxisKey::BarWidth(_) => (),
+        AxisKey::BarShift(_) => (),
+        AxisKey::SymbolicXCoords(_) => (),
+        AxisKey::GridStyle(_) => (),
+        AxisKey::EnlargeLimits(_) => (),
+        AxisKey::Clip(_) => (),
+        AxisKey::AxisOnTop(_) => (),
+        AxisKey::XTick(_) => (),
+        AxisKey::XTickLabels(_) => (),
+        AxisKey::XTickLabelStyle(_) => (),
+        AxisKey::LegendImagePostStyle(_) => (),
+        AxisKey::EnlargeYLimitsUpper(_) => (),
+        AxisKey::EnlargeYLimitsLower(_) => (),
+        AxisKey::XTickMin(_) => (),
+        AxisKey::XTickMax(_) => (),
+        AxisKey::YTickMin(_) => (),
+        AxisKey::YTickMax(_) => (),
+        AxisKey::RestrictYToDomain(_, _) => (),
+        AxisKey::UnboundedCoords(_) => (),
+        AxisKey::TickStyle(_) => (),
+        AxisKey::ColorbarPosition(_) => (),
+        AxisKey::XScaleTransform { .. } => (),
+        AxisKey::LogBasisX(_) => (),
+        AxisKey::LogBasisY(_) => (),
+        AxisKey::ExtraDescription(_) => (),
+        AxisKey::YLabelStyle(_) => (),
+        AxisKey::ColorbarStyle(_) => (),
+        AxisKey::EveryNodeStyle(_) => (),
+        AxisKey::XLabelShift(_) => (),
+        AxisKey::YLabelShift(_) => (),
+        AxisKey::MarkSize(_) => (),
+        AxisKey::BarCycleList(_) => (),
+        AxisKey::ClipLimits(_) => (),
+        AxisKey::LegendToName(_) => (),
+        AxisKey::YTick(_) => (),
+        AxisKey::LegendCellAlign(_) => (),
+        AxisKey::Grid(_) => (),
+        AxisKey::EnlargeXLimitsAbs(_) => (),
+        AxisKey::EnlargeYLimitsAbs(_) => (),
+    }
+}
+
+#[test]
+fn axis_key_enlarge_x_limits_abs_to_string() {
+    assert_eq!(
+        AxisKey::EnlargeXLimitsAbs(0.5).to_string(),
+        String::from("enlarge x limits={abs=0.5}")
+    );
+    assert_eq!(
+        AxisKey::EnlargeXLimitsAbs(2.0).to_string(),
+        String::from("enlarge x limits={abs=2}")
+    );
+}
+
+#[test]
+fn axis_key_enlarge_y_limits_abs_to_string() {
+    assert_eq!(
+        AxisKey::EnlargeYLimitsAbs(0.5).to_string(),
+        String::from("enlarge y limits={abs=0.5}")
+    );
+
+    let mut axis = Axis::new();
+    axis.add_key(AxisKey::EnlargeXLimitsAbs(1.0));
+    axis.add_key(AxisKey::EnlargeYLimitsAbs(1.0));
+    axis.add_key(AxisKey::EnlargeYLimitsAbs(0.25));
+    assert_eq!(axis.keys.len(), 2);
+    assert_eq!(
+        axis.keys[1].to_string(),
+        String::from("enlarge y limits={abs=0.25}")
+    );
+}
+
+#[test]
+fn axis_key_grid_to_string() {
+    assert_eq!(
+        AxisKey::Grid(Grid::Major).to_string(),
+        String::from("grid=major")
+    );
+    assert_eq!(
+        AxisKey::Grid(Grid::Both).to_string(),
+        String::from("grid=both")
+    );
+}
+
+#[test]
+fn axis_key_legend_cell_align_to_string() {
+    assert_eq!(
+        AxisKey::LegendCellAlign(Align::Left).to_string(),
+        String::from("legend cell align=left")
+    );
+    assert_eq!(
+        AxisKey::LegendCellAlign(Align::Right).to_string(),
+        String::from("legend cell align=right")
+    );
+    assert_eq!(
+        AxisKey::LegendCellAlign(Align::Center).to_string(),
+        String::from("legend cell align=center")
+    );
+}
+
+#[test]
+fn axis_key_y_tick_to_string() {
+    assert_eq!(
+        AxisKey::YTick(Ticks::Data).to_string(),
+        String::from("ytick=data")
+    );
+    assert_eq!(
+        AxisKey::YTick(Ticks::Values(vec![-1.0, 0.5])).to_string(),
+        String::from("ytick={-1, 0.5}")
+    );
+
+    let mut axis = Axis::new();
+    axis.add_key(AxisKey::XTick(Ticks::Data));
+    axis.add_key(AxisKey::YTick(Ticks::Values(vec![1.0])));
+    axis.add_key(AxisKey::YTick(Ticks::Data));
+    assert_eq!(axis.keys.len(), 2);
+    assert_eq!(axis.keys[1].to_string(), String::from("ytick=data"));
+}
+
+#[test]
+fn axis_key_legend_to_name_to_string() {
+    assert_eq!(
+        AxisKey::LegendToName(String::from("named-legend")).to_string(),
+        String::from("legend to name=named-legend")
+    );
+}
+
+#[test]
+fn axis_key_clip_limits_to_string() {
+    assert_eq!(
+        AxisKey::ClipLimits(true).to_string(),
+        String::from("clip limits=true")
+    );
+    assert_eq!(
+        AxisKey::ClipLimits(false).to_string(),
+        String::from("clip limits=false")
+    );
+
+    let mut axis = Axis::new();
+    axis.add_key(AxisKey::Clip(false));
+    axis.add_key(AxisKey::ClipLimits(true));
+    axis.add_key(AxisKey::ClipLimits(false));
+    assert_eq!(axis.keys.len(), 2);
+    assert_eq!(axis.keys[1].to_string(), String::from("clip limits=false"));
+}
+
+#[test]
+fn axis_key_bar_cycle_list_to_string() {
+    assert_eq!(
+        AxisKey::BarCycleList(vec![
+            String::from("blue, fill=blue!30"),
+            String::from("red, fill=red!30"),
+        ])
+        .to_string(),
+        String::from(
+            "bar cycle list/.style={/pgfplots/cycle list={{blue, fill=blue!30},{red, fill=red!30}}}"
+        )
+    );
+
+    let mut axis = Axis::new();
+    axis.add_key(AxisKey::BarCycleList(vec![String::from("blue")]));
+    axis.add_key(AxisKey::BarCycleList(vec![String::from("red")]));
+    assert_eq!(axis.keys.len(), 1);
+    assert_eq!(
+        axis.keys[0].to_string(),
+        String::from("bar cycle list/.style={/pgfplots/cycle list={{red}}}")
+    );
+}
+
+#[test]
+fn axis_key_mark_size_to_string() {
+    assert_eq!(
+        AxisKey::MarkSize(Dimension::Pt(1.5)).to_string(),
+        String::from("mark size=1.5pt")
+    );
+}
+
+#[test]
+fn axis_key_x_label_shift_to_string() {
+    assert_eq!(
+        AxisKey::XLabelShift(Dimension::Pt(-5.0)).to_string(),
+        String::from("xlabel shift=-5pt")
+    );
+    assert_eq!(
+        AxisKey::XLabelShift(Dimension::Mm(2.0)).to_string(),
+        String::from("xlabel shift=2mm")
+    );
+}
+
+#[test]
+fn axis_key_y_label_shift_to_string() {
+    assert_eq!(
+        AxisKey::YLabelShift(Dimension::Pt(-5.0)).to_string(),
+        String::from("ylabel shift=-5pt")
+    );
+    assert_eq!(
+        AxisKey::YLabelShift(Dimension::Em(0.5)).to_string(),
+        String::from("ylabel shift=0.5em")
+    );
+}
+
+#[test]
+fn axis_key_every_node_style_to_string() {
+    assert_eq!(
+        AxisKey::EveryNodeStyle(vec![String::from("font=\\small")]).to_string(),
+        String::from("every node/.style={font=\\small}")
+    );
+}
+
+#[test]
+fn axis_key_colorbar_style_to_string() {
+    assert_eq!(
+        AxisKey::ColorbarStyle(vec![
+            String::from("width=0.3cm"),
+            String::from("ytick={0,1}")
+        ])
+        .to_string(),
+        String::from("colorbar style={width=0.3cm, ytick={0,1}}")
+    );
+}
+
+#[test]
+fn axis_key_y_label_style_to_string() {
+    assert_eq!(
+        AxisKey::YLabelStyle(vec![
+            String::from("rotate=-90"),
+            String::from("align=center")
+        ])
+        .to_string(),
+        String::from("ylabel style={rotate=-90, align=center}")
+    );
+}
+
+#[test]
+fn axis_key_extra_description_to_string() {
+    assert_eq!(
+        AxisKey::ExtraDescription(String::from("\\node at (0,0) {A};")).to_string(),
+        String::from("extra description/.code={\\node at (0,0) {A};}")
+    );
+}
+
+#[test]
+fn axis_key_log_basis_x_to_string() {
+    assert_eq!(
+        AxisKey::LogBasisX(10.0).to_string(),
+        String::from("log basis x=10")
+    );
+}
+
+#[test]
+fn axis_key_log_basis_y_to_string() {
+    assert_eq!(
+        AxisKey::LogBasisY(2.0).to_string(),
+        String::from("log basis y=2")
+    );
+}
+
+#[test]
+fn axis_key_x_scale_transform_to_string() {
+    assert_eq!(
+        AxisKey::XScaleTransform {
+            trafo: String::from("\\pgfmathparse{sqrt(#1)}"),
+            inv_trafo: String::from("\\pgfmathparse{(#1)^2}"),
+        }
+        .to_string(),
+        String::from(
+            "x coord trafo/.code={\\pgfmathparse{sqrt(#1)}}, x coord inv trafo/.code={\\pgfmathparse{(#1)^2}}"
+        )
+    );
+}
+
+#[test]
+fn axis_key_colorbar_position_to_string() {
+    assert_eq!(
+        AxisKey::ColorbarPosition(ColorbarPos::Right).to_string(),
+        String::from("colorbar right")
+    );
+    assert_eq!(
+        AxisKey::ColorbarPosition(ColorbarPos::Bottom).to_string(),
+        String::from("colorbar horizontal")
+    );
+}
+
+#[test]
+fn axis_key_tick_style_to_string() {
+    assert_eq!(
+        AxisKey::TickStyle(vec![
+            String::from("line width=0.4pt"),
+            String::from("black")
+        ])
+        .to_string(),
+        String::from("tick style={line width=0.4pt, black}")
+    );
+}
+
+#[test]
+fn axis_key_restrict_y_to_domain_to_string() {
+    assert_eq!(
+        AxisKey::RestrictYToDomain(-10.0, 10.5).to_string(),
+        String::from("restrict y to domain=-10:10.5")
+    );
+}
+
+#[test]
+fn axis_key_unbounded_coords_to_string() {
+    assert_eq!(
+        AxisKey::UnboundedCoords(UnboundedCoords::Jump).to_string(),
+        String::from("unbounded coords=jump")
+    );
+}
+
+#[test]
+fn axis_key_x_tick_min_to_string() {
+    assert_eq!(
+        AxisKey::XTickMin(0.0).to_string(),
+        String::from("xtick min=0")
+    );
+}
+
+#[test]
+fn axis_key_x_tick_max_to_string() {
+    assert_eq!(
+        AxisKey::XTickMax(10.0).to_string(),
+        String::from("xtick max=10")
+    );
+}
+
+#[test]
+fn axis_key_y_tick_min_to_string() {
+    assert_eq!(
+        AxisKey::YTickMin(-2.5).to_string(),
+        String::from("ytick min=-2.5")
+    );
+}
+
+#[test]
+fn axis_key_y_tick_max_to_string() {
+    assert_eq!(
+        AxisKey::YTickMax(1e3).to_string(),
+        String::from("ytick max=1000")
+    );
+}
+
+#[test]
+fn axis_key_enlarge_y_limits_upper_to_string() {
+    assert_eq!(
+        AxisKey::EnlargeYLimitsUpper(0.2).to_string(),
+        String::from("enlarge y limits={value=0.2,upper}")
+    );
+}
+
+#[test]
+fn axis_key_enlarge_y_limits_lower_to_string() {
+    assert_eq!(
+        AxisKey::EnlargeYLimitsLower(0.0).to_string(),
+        String::from("enlarge y limits={value=0,lower}")
+    );
+}
+
+#[test]
+fn axis_add_key_enlarge_y_limits() {
+    let mut axis = Axis::new();
+    axis.add_key(AxisKey::EnlargeLimits(false));
+    axis.add_key(AxisKey::EnlargeYLimitsUpper(0.2));
+    axis.add_key(AxisKey::EnlargeYLimitsLower(0.1));
+    assert_eq!(axis.keys.len(), 2);
+    assert_eq!(
+        axis.keys[0].to_string(),
+        String::from("enlargelimits=false")
+    );
+    assert_eq!(
+        axis.keys[1].to_string(),
+        String::from("enlarge y limits={value=0.1,lower}")
+    );
+
+    axis.add_key(AxisKey::EnlargeYLimitsAbs(0.5));
+    assert_eq!(axis.keys.len(), 2);
+    assert_eq!(
+        axis.keys[1].to_string(),
+        String::from("enlarge y limits={abs=0.5}")
+    );
+
+    axis.add_key(AxisKey::EnlargeYLimitsUpper(0.3));
+    assert_eq!(axis.keys.len(), 2);
+    assert_eq!(
+        axis.keys[1].to_string(),
+        String::from("enlarge y limits={value=0.3,upper}")
+    );
+}
+
+#[test]
+fn axis_add_key_colorbar_position_top() {
+    let mut axis = Axis::new();
+    axis.add_key(AxisKey::ColorbarStyle(vec![
+        String::from("height=0.3cm"),
+        String::from("anchor=north"),
+    ]));
+    axis.add_key(AxisKey::ColorbarPosition(ColorbarPos::Top));
+    assert_eq!(axis.keys.len(), 2);
+    assert_eq!(
+        axis.keys[0].to_string(),
+        String::from(
+            "colorbar style={height=0.3cm, anchor=north, at={(0.5,1.03)}, xticklabel pos=upper}"
+        )
+    );
+    assert_eq!(
+        axis.keys[1].to_string(),
+        String::from("colorbar horizontal")
+    );
+
+    axis.add_key(AxisKey::ColorbarStyle(vec![String::from("width=5cm")]));
+    assert_eq!(axis.keys.len(), 2);
+    assert_eq!(
+        axis.keys[1].to_string(),
+        String::from(
+            "colorbar style={width=5cm, at={(0.5,1.03)}, anchor=south, xticklabel pos=upper}"
+        )
+    );
+
+    axis.add_key(AxisKey::ColorbarPosition(ColorbarPos::Bottom));
+    assert_eq!(axis.keys.len(), 2);
+    assert_eq!(
+        axis.keys[0].to_string(),
+        String::from("colorbar style={width=5cm}")
+    );
+    assert_eq!(
+        axis.keys[1].to_string(),
+        String::from("colorbar horizontal")
+    );
+}
+
+#[test]
+fn axis_key_legend_image_post_style_to_string() {
+    assert_eq!(
+        AxisKey::LegendImagePostStyle(vec![String::from("mark=none")]).to_string(),
+        String::from("legend image post style={mark=none}")
+    );
+}
+
+#[test]
+fn axis_key_x_tick_to_string() {
+    assert_eq!(
+        AxisKey::XTick(Ticks::Data).to_string(),
+        String::from("xtick=data")
+    );
+    assert_eq!(
+        AxisKey::XTick(Ticks::Values(vec![0.0, 1.5, 3.0])).to_string(),
+        String::from("xtick={0, 1.5, 3}")
+    );
+}
+
+#[test]
+fn axis_key_x_tick_labels_to_string() {
+    assert_eq!(
+        AxisKey::XTickLabels(vec![String::from("a"), String::from("b")]).to_string(),
+        String::from("xticklabels={a, b}")
+    );
+}
+
+#[test]
+fn axis_key_x_tick_label_style_to_string() {
+    assert_eq!(
+        AxisKey::XTickLabelStyle(vec![String::from("rotate=45"), String::from("anchor=east")])
+            .to_string(),
+        String::from("xticklabel style={rotate=45, anchor=east}")
+    );
+}
+
+#[test]
+fn axis_key_axis_on_top_to_string() {
+    assert_eq!(
+        AxisKey::AxisOnTop(true).to_string(),
+        String::from("axis on top=true")
+    );
+    assert_eq!(
+        AxisKey::AxisOnTop(false).to_string(),
+        String::from("axis on top=false")
+    );
+}
+
+#[test]
+fn axis_key_enlarge_limits_to_string() {
+    assert_eq!(
+        AxisKey::EnlargeLimits(false).to_string(),
+        String::from("enlargelimits=false")
+    );
+    assert_eq!(
+        AxisKey::EnlargeLimits(true).to_string(),
+        String::from("enlargelimits=true")
+    );
+}
+
+#[test]
+fn axis_key_clip_to_string() {
+    assert_eq!(AxisKey::Clip(true).to_string(), String::from("clip=true"));
+    assert_eq!(AxisKey::Clip(false).to_string(), String::from("clip=false"));
+}
+
+#[test]
+fn axis_key_grid_style_to_string() {
+    assert_eq!(
+        AxisKey::GridStyle(vec![String::from("dashed"), String::from("gray!30")]).to_string(),
+        String::from("grid style={dashed, gray!30}")
+    );
+}
+
+#[test]
+fn axis_key_symbolic_x_coords_to_string() {
+    assert_eq!(
+        AxisKey::SymbolicXCoords(vec![String::from("apples"), String::from("oranges")]).to_string(),
+        String::from("symbolic x coords={apples, oranges}")
+    );
+}
+
+#[test]
+fn axis_key_bar_width_to_string() {
+    assert_eq!(
+        AxisKey::BarWidth(Dimension::Pt(10.0)).to_string(),
+        String::from("bar width=10pt")
+    );
+}
+
+#[test]
+fn axis_key_bar_shift_to_string() {
+    assert_eq!(
+        AxisKey::BarShift(Dimension::Pt(-5.5)).to_string(),
+        String::from("bar shift=-5.5pt")
+    );
+}
+
+#[test]
+fn axis_key_clip_marker_paths_to_string() {
+    assert_eq!(
+        AxisKey::ClipMarkerPaths(true).to_string(),
+        String::from("clip marker paths=true")
+    );
+    assert_eq!(
+        AxisKey::ClipMarkerPaths(false).to_string(),
+        String::from("clip marker paths=false")
+    );
+}
+
+#[test]
+fn axis_key_width_to_string() {
+    assert_eq!(
+        AxisKey::Width(Dimension::Cm(8.0)).to_string(),
+        String::from("width=8cm")
+    );
+    assert_eq!(
+        AxisKey::Width(Dimension::TextWidth(0.8)).to_string(),
+        String::from("width=0.8\\textwidth")
+    );
+}
+
+#[test]
+fn axis_key_height_to_string() {
+    assert_eq!(
+        AxisKey::Height(Dimension::Pt(120.5)).to_string(),
+        String::from("height=120.5pt")
+    );
+}
+
+#[test]
+fn axis_key_scale_only_axis_to_string() {
+    assert_eq!(
+        AxisKey::ScaleOnlyAxis(true).to_string(),
+        String::from("scale only axis=true")
+    );
+    assert_eq!(
+        AxisKey::ScaleOnlyAxis(false).to_string(),
+        String::from("scale only axis=false")
+    );
+}
+
+#[test]
+fn axis_key_x_min_to_string() {
+    assert_eq!(AxisKey::XMin(0.0).to_string(), String::from("xmin=0"));
+    assert_eq!(AxisKey::XMin(-1.5).to_string(), String::from("xmin=-1.5"));
+}
+
+#[test]
+fn axis_key_x_max_to_string() {
+    assert_eq!(AxisKey::XMax(10.0).to_string(), String::from("xmax=10"));
+    assert_eq!(AxisKey::XMax(-1.5).to_string(), String::from("xmax=-1.5"));
+}
+
+#[test]
+fn axis_key_y_min_to_string() {
+    assert_eq!(AxisKey::YMin(0.0).to_string(), String::from("ymin=0"));
+    assert_eq!(AxisKey::YMin(-1.5).to_string(), String::from("ymin=-1.5"));
+}
+
+#[test]
+fn axis_key_y_max_to_string() {
+    assert_eq!(AxisKey::YMax(10.0).to_string(), String::from("ymax=10"));
+    assert_eq!(AxisKey::YMax(-1.5).to_string(), String::from("ymax=-1.5"));
+}
+
+#[test]
+fn axis_key_view_to_string() {
+    assert_eq!(
+        AxisKey::View(60.0, 30.0).to_string(),
+        String::from("view={60}{30}")
+    );
+    assert_eq!(
+        AxisKey::View(-37.5, 22.5).to_string(),
+        String::from("view={-37.5}{22.5}")
+    );
+}
+
+#[test]
+fn axis_key_define_colormap_to_string() {
+    assert_eq!(
+        AxisKey::DefineColormap(Colormap::custom(
+            "mymap",
+            [PredefinedColor::Red, PredefinedColor::Blue]
+        ))
+        .to_string(),
+        String::from("colormap={mymap}{color=(red) color=(blue)}")
+    );
+}
+
+#[test]
+fn axis_key_title_style_to_string() {
+    assert_eq!(
+        AxisKey::TitleStyle(vec![
+            String::from("anchor=north"),
+            String::from("yshift=-1em")
+        ])
+        .to_string(),
+        String::from("title style={anchor=north, yshift=-1em}")
+    );
+}
+
+#[test]
+fn axis_key_legend_columns_to_string() {
+    assert_eq!(
+        AxisKey::LegendColumns(3).to_string(),
+        String::from("legend columns=3")
+    );
+    assert_eq!(
+        AxisKey::LegendColumns(-1).to_string(),
+        String::from("legend columns=-1")
+    );
+}
+
+#[test]
+fn axis_key_x_dir_to_string() {
+    assert_eq!(
+        AxisKey::XDir(AxisDir::Normal).to_string(),
+        String::from("x dir=normal")
+    );
+    assert_eq!(
+        AxisKey::XDir(AxisDir::Reverse).to_string(),
+        String::from("x dir=reverse")
+    );
+}
+
+#[test]
+fn axis_key_y_dir_to_string() {
+    assert_eq!(
+        AxisKey::YDir(AxisDir::Normal).to_string(),
+        String::from("y dir=normal")
+    );
+    assert_eq!(
+        AxisKey::YDir(AxisDir::Reverse).to_string(),
+        String::from("y dir=reverse")
+    );
+}
+
+#[test]
+fn axis_key_y_tick_label_style_to_string() {
+    assert_eq!(
+        AxisKey::YTickLabelStyle(vec![
+            String::from("text width=1cm"),
+            String::from("align=right")
+        ])
+        .to_string(),
+        String::from("yticklabel style={text width=1cm, align=right}")
+    );
+}
+
+#[test]
+fn axis_key_extra_x_tick_style_to_string() {
+    assert_eq!(
+        AxisKey::ExtraXTickStyle(vec![String::from("grid=major"), String::from("red")]).to_string(),
+        String::from("extra x tick style={grid=major, red}")
+    );
+    assert_eq!(
+        AxisKey::ExtraXTickStyle(Vec::new()).to_string(),
+        String::from("extra x tick style={}")
+    );
+}
+
+#[test]
+fn axis_key_extra_x_ticks_to_string() {
+    assert_eq!(
+        AxisKey::ExtraXTicks(vec![1.5, 3.2]).to_string(),
+        String::from("extra x ticks={1.5, 3.2}")
+    );
+    assert_eq!(
+        AxisKey::ExtraXTicks(vec![-1.0]).to_string(),
+        String::from("extra x ticks={-1}")
+    );
+}
+
+#[test]
+fn axis_key_extra_y_ticks_to_string() {
+    assert_eq!(
+        AxisKey::ExtraYTicks(vec![1.5, 3.2]).to_string(),
+        String::from("extra y ticks={1.5, 3.2}")
+    );
+    assert_eq!(
+        AxisKey::ExtraYTicks(vec![-1.0]).to_string(),
+        String::from("extra y ticks={-1}")
+    );
 }
 
 #[test]
@@ -83,11 +904,36 @@ fn axis_key_y_mode_to_string() {
     );
 }
 
+#[test]
+fn colormap_custom() {
+    let colormap = Colormap::custom(
+        String::from("mymap"),
+        vec![
+            Color::from(PredefinedColor::Red),
+            Color::from(PredefinedColor::Green),
+            Color::from(PredefinedColor::Blue),
+        ],
+    );
+    assert_eq!(colormap.name, "mymap");
+    assert_eq!(colormap.colors.len(), 3);
+    assert_eq!(colormap.colors[2].to_string(), String::from("blue"));
+}
+
 #[test]
 fn axis_new() {
     let axis = Axis::new();
     assert!(axis.plots.is_empty());
     assert!(axis.keys.is_empty());
+    assert!(axis.annotations.is_empty());
+}
+
+#[test]
+fn axis_with_capacity() {
+    let axis = Axis::with_capacity(8);
+    assert!(axis.plots.is_empty());
+    assert!(axis.plots.capacity() >= 8);
+    assert!(axis.keys.is_empty());
+    assert!(axis.annotations.is_empty());
 }
 
 #[test]
@@ -98,6 +944,40 @@ fn axis_set_title() {
     assert!(matches!(axis.keys[0], AxisKey::Title(_)));
 }
 
+#[test]
+fn axis_set_title_below() {
+    let mut axis = Axis::new();
+    axis.set_title_below("Something");
+    assert_eq!(axis.keys.len(), 2);
+    assert_eq!(axis.keys[0].to_string(), String::from("title={Something}"));
+    assert_eq!(
+        axis.keys[1].to_string(),
+        String::from("title style={at={(0.5,0)}, anchor=north, yshift=-3em}")
+    );
+
+    axis.set_title_below("Other");
+    assert_eq!(axis.keys.len(), 2);
+    assert_eq!(axis.keys[0].to_string(), String::from("title={Other}"));
+}
+
+#[test]
+fn axis_set_title_font_size() {
+    let mut axis = Axis::new();
+    axis.set_title_font_size(FontSize::Large);
+    assert_eq!(axis.keys.len(), 1);
+    assert_eq!(
+        axis.keys[0].to_string(),
+        String::from("title style={font=\\Large}")
+    );
+
+    axis.set_title_font_size(FontSize::Small);
+    assert_eq!(axis.keys.len(), 1);
+    assert_eq!(
+        axis.keys[0].to_string(),
+        String::from("title style={font=\\small}")
+    );
+}
+
 #[test]
 fn axis_set_x_label() {
     let mut axis = Axis::new();
@@ -114,6 +994,330 @@ fn axis_set_y_label() {
     assert!(matches!(axis.keys[0], AxisKey::YLabel(_)));
 }
 
+#[test]
+fn axis_set_y_tick_labels_right_aligned() {
+    let mut axis = Axis::new();
+    axis.set_y_tick_labels_right_aligned(Dimension::Cm(1.5));
+    assert_eq!(axis.keys.len(), 1);
+    assert_eq!(
+        axis.keys[0].to_string(),
+        String::from("yticklabel style={text width=1.5cm, align=right}")
+    );
+}
+
+#[test]
+fn axis_set_y_tick_precision() {
+    let mut axis = Axis::new();
+    axis.set_y_tick_labels_right_aligned(Dimension::Cm(1.5));
+    axis.set_y_tick_precision(2);
+    assert_eq!(axis.keys.len(), 1);
+    assert_eq!(
+        axis.keys[0].to_string(),
+        String::from("yticklabel style={/pgf/number format/fixed, /pgf/number format/precision=2}")
+    );
+}
+
+#[test]
+fn axis_set_aspect_ratio() {
+    let mut axis = Axis::new();
+    axis.set_aspect_ratio(Dimension::Cm(8.0), 1.6);
+    assert_eq!(axis.keys.len(), 3);
+    assert_eq!(axis.keys[0].to_string(), String::from("width=8cm"));
+    assert_eq!(axis.keys[1].to_string(), String::from("height=5cm"));
+    assert_eq!(
+        axis.keys[2].to_string(),
+        String::from("scale only axis=true")
+    );
+}
+
+#[test]
+fn axis_set_bar_baseline_zero() {
+    let mut axis = Axis::new();
+    axis.add_key(AxisKey::YMin(5.0));
+    axis.set_bar_baseline_zero();
+    assert_eq!(axis.keys.len(), 1);
+    assert_eq!(axis.keys[0].to_string(), String::from("ymin=0"));
+}
+
+#[test]
+fn axis_set_colorbar_label() {
+    let mut axis = Axis::new();
+    axis.set_colorbar_label("$\\rho$, $x=1$~[kg/m$^3$]");
+    assert_eq!(axis.keys.len(), 1);
+    assert_eq!(
+        axis.keys[0].to_string(),
+        String::from("colorbar style={ylabel={$\\rho$, $x=1$~[kg/m$^3$]}}")
+    );
+
+    axis.set_colorbar_label("$T$~[K]");
+    assert_eq!(axis.keys.len(), 1);
+    assert_eq!(
+        axis.keys[0].to_string(),
+        String::from("colorbar style={ylabel={$T$~[K]}}")
+    );
+}
+
+#[test]
+fn axis_set_colorbar_label_horizontal() {
+    let mut axis = Axis::new();
+    axis.add_key(AxisKey::ColorbarPosition(ColorbarPos::Bottom));
+    axis.add_key(AxisKey::ColorbarStyle(vec![String::from("height=0.3cm")]));
+    axis.set_colorbar_label("$T$~[K]");
+    assert_eq!(axis.keys.len(), 2);
+    assert_eq!(
+        axis.keys[1].to_string(),
+        String::from("colorbar style={height=0.3cm, xlabel={$T$~[K]}}")
+    );
+}
+
+#[test]
+fn axis_set_y_label_horizontal() {
+    let mut axis = Axis::new();
+    axis.set_y_label("Old");
+    axis.set_y_label_horizontal("Height\\\\(m)");
+    assert_eq!(axis.keys.len(), 2);
+    assert_eq!(
+        axis.keys[0].to_string(),
+        String::from("ylabel={Height\\\\(m)}")
+    );
+    assert_eq!(
+        axis.keys[1].to_string(),
+        String::from("ylabel style={rotate=-90, align=center}")
+    );
+}
+
+#[test]
+fn axis_set_log_x() {
+    let mut axis = Axis::new();
+    axis.add_key(AxisKey::XMode(Scale::Normal));
+    axis.set_log_x(10.0);
+    assert_eq!(axis.keys.len(), 2);
+    assert_eq!(axis.keys[0].to_string(), String::from("xmode=log"));
+    assert_eq!(axis.keys[1].to_string(), String::from("log basis x=10"));
+}
+
+#[test]
+fn axis_set_log_y() {
+    let mut axis = Axis::new();
+    axis.set_log_y(2.0);
+    assert_eq!(axis.keys.len(), 2);
+    assert_eq!(axis.keys[0].to_string(), String::from("ymode=log"));
+    assert_eq!(axis.keys[1].to_string(), String::from("log basis y=2"));
+}
+
+#[test]
+fn axis_set_x_limits() {
+    let mut axis = Axis::new();
+    axis.add_key(AxisKey::XMin(5.0));
+    axis.set_x_limits(-1.0, 1.0);
+    assert_eq!(axis.keys.len(), 2);
+    assert_eq!(axis.keys[0].to_string(), String::from("xmin=-1"));
+    assert_eq!(axis.keys[1].to_string(), String::from("xmax=1"));
+}
+
+#[test]
+fn axis_set_y_limits() {
+    let mut axis = Axis::new();
+    axis.add_key(AxisKey::YMax(5.0));
+    axis.set_y_limits(0.0, 10.0);
+    assert_eq!(axis.keys.len(), 2);
+    assert_eq!(axis.keys[0].to_string(), String::from("ymin=0"));
+    assert_eq!(axis.keys[1].to_string(), String::from("ymax=10"));
+}
+
+#[test]
+fn axis_set_limits() {
+    let mut axis = Axis::new();
+    axis.set_limits(-1.0, 1.0, 0.0, 10.0);
+    assert_eq!(axis.keys.len(), 4);
+    assert_eq!(axis.keys[0].to_string(), String::from("xmin=-1"));
+    assert_eq!(axis.keys[1].to_string(), String::from("xmax=1"));
+    assert_eq!(axis.keys[2].to_string(), String::from("ymin=0"));
+    assert_eq!(axis.keys[3].to_string(), String::from("ymax=10"));
+}
+
+#[test]
+fn axis_clip_to_y_range() {
+    let mut axis = Axis::new();
+    axis.clip_to_y_range(-10.0, 10.0);
+    assert_eq!(axis.keys.len(), 4);
+    assert_eq!(
+        axis.keys[0].to_string(),
+        String::from("restrict y to domain=-10:10")
+    );
+    assert_eq!(
+        axis.keys[1].to_string(),
+        String::from("unbounded coords=jump")
+    );
+    assert_eq!(axis.keys[2].to_string(), String::from("ymin=-10"));
+    assert_eq!(axis.keys[3].to_string(), String::from("ymax=10"));
+}
+
+#[test]
+fn axis_set_panel_label() {
+    let mut axis = Axis::new();
+    axis.set_panel_label("(a)", Corner::NorthWest);
+    assert_eq!(axis.keys.len(), 1);
+    assert_eq!(
+        axis.keys[0].to_string(),
+        String::from(
+            "extra description/.code={\\node[anchor=north west] at (rel axis cs:0,1) {(a)};}"
+        )
+    );
+
+    axis.set_panel_label("(b)", Corner::SouthEast);
+    assert_eq!(axis.keys.len(), 1);
+    assert_eq!(
+        axis.keys[0].to_string(),
+        String::from(
+            "extra description/.code={\\node[anchor=south east] at (rel axis cs:1,0) {(b)};}"
+        )
+    );
+}
+
+#[test]
+fn axis_add_text_annotation() {
+    let mut axis = Axis::from(Plot2D::new());
+    axis.add_text_annotation(0.5, 0.9, "Text");
+    axis.add_text_annotation(0.1, 0.0, "$x^2$");
+    assert!(axis.keys.is_empty());
+    assert_eq!(
+        axis.to_string(),
+        "\\begin{axis}\n\t\\addplot[] coordinates {\n\t};\n\t\\node at (rel axis cs:0.5,0.9) {Text};\n\t\\node at (rel axis cs:0.1,0) {$x^2$};\n\\end{axis}"
+    );
+}
+
+#[test]
+fn axis_set_subtle_grid() {
+    let mut axis = Axis::new();
+    axis.add_key(AxisKey::AxisOnTop(true));
+    axis.set_subtle_grid();
+    axis.set_subtle_grid();
+    assert_eq!(axis.keys.len(), 3);
+    assert_eq!(axis.keys[0].to_string(), String::from("grid=both"));
+    assert_eq!(
+        axis.keys[1].to_string(),
+        String::from("grid style={gray!20, dashed}")
+    );
+    assert_eq!(axis.keys[2].to_string(), String::from("axis on top=false"));
+}
+
+#[test]
+fn axis_tight_layout() {
+    let mut axis = Axis::new();
+    axis.add_key(AxisKey::Clip(false));
+    axis.tight_layout();
+    axis.tight_layout();
+    assert_eq!(axis.keys.len(), 3);
+    assert_eq!(
+        axis.keys[0].to_string(),
+        String::from("enlargelimits=false")
+    );
+    assert_eq!(axis.keys[1].to_string(), String::from("axis on top=true"));
+    assert_eq!(axis.keys[2].to_string(), String::from("clip=true"));
+}
+
+#[test]
+fn axis_set_legend_to_name() {
+    let mut axis = Axis::new();
+    axis.set_legend_to_name("first");
+    axis.set_legend_to_name("named-legend");
+    assert_eq!(axis.keys.len(), 1);
+    assert_eq!(
+        axis.keys[0].to_string(),
+        String::from("legend to name=named-legend")
+    );
+}
+
+#[test]
+fn axis_num_coordinates() {
+    let mut axis = Axis::new();
+    assert_eq!(axis.num_coordinates(), 0);
+
+    axis.push_plot(Plot2D::from_xy(&[1.0, 2.0, 3.0], &[1.0, 2.0, 3.0]).unwrap());
+    axis.push_plot(Plot2D::new());
+    let mut symbolic = Plot2D::new();
+    symbolic.symbolic_coordinates = vec![("a", 1.0).into(), ("b", 2.0).into()];
+    axis.push_plot(symbolic);
+    assert_eq!(axis.num_coordinates(), 5);
+}
+
+#[test]
+fn axis_auto_scale() {
+    let plot = Plot2D::from_xy(&[1.0, 2.0, 3.0], &[0.5, 20.0, 5000.0]).unwrap();
+    let mut axis = Axis::from(plot);
+    axis.auto_scale(3.0);
+    assert_eq!(axis.keys.len(), 1);
+    assert_eq!(axis.keys[0].to_string(), String::from("ymode=log"));
+
+    // Narrower range than the threshold
+    let plot = Plot2D::from_xy(&[1.0, 2.0], &[1.0, 500.0]).unwrap();
+    let mut axis = Axis::from(plot);
+    axis.auto_scale(3.0);
+    assert!(axis.keys.is_empty());
+
+    // Not strictly positive
+    let plot = Plot2D::from_xy(&[1.0, 2.0, 3.0], &[0.0, 1.0, 1e6]).unwrap();
+    let mut axis = Axis::from(plot);
+    axis.auto_scale(3.0);
+    assert!(axis.keys.is_empty());
+
+    // No data
+    let mut axis = Axis::new();
+    axis.auto_scale(3.0);
+    assert!(axis.keys.is_empty());
+}
+
+#[test]
+fn axis_auto_rotate_x_ticks() {
+    let mut axis = Axis::new();
+    axis.auto_rotate_x_ticks(&["January", "February"], 5);
+    assert_eq!(axis.keys.len(), 3);
+    assert_eq!(axis.keys[0].to_string(), String::from("xtick=data"));
+    assert_eq!(
+        axis.keys[1].to_string(),
+        String::from("xticklabels={January, February}")
+    );
+    assert_eq!(
+        axis.keys[2].to_string(),
+        String::from("xticklabel style={rotate=45, anchor=east}")
+    );
+
+    let mut axis = Axis::new();
+    axis.auto_rotate_x_ticks(&["Jan", "Feb"], 3);
+    assert_eq!(axis.keys.len(), 2);
+    assert_eq!(axis.keys[0].to_string(), String::from("xtick=data"));
+    assert_eq!(
+        axis.keys[1].to_string(),
+        String::from("xticklabels={Jan, Feb}")
+    );
+}
+
+#[test]
+fn axis_push_plot() {
+    let mut axis = Axis::from(Plot2D::new());
+    let plot = axis.push_plot(Plot2D::new());
+    plot.coordinates.push((1.0, 2.0).into());
+
+    assert_eq!(axis.plots.len(), 2);
+    assert!(axis.plots[0].coordinates.is_empty());
+    assert_eq!(axis.plots[1].coordinates.len(), 1);
+}
+
+#[test]
+fn axis_clear() {
+    let mut axis = Axis::from(Plot2D::new());
+    axis.add_key(AxisKey::Clip(false));
+    axis.set_title("Title");
+
+    axis.add_text_annotation(0.5, 0.5, "Text");
+
+    axis.clear();
+    assert!(axis.plots.is_empty());
+    assert!(axis.keys.is_empty());
+    assert!(axis.annotations.is_empty());
+}
+
 #[test]
 fn axis_add_key() {
     let mut axis = Axis::new();
@@ -0,0 +1,132 @@
+use std::fmt;
+use std::ops::{Div, Mul, Neg};
+
+/// Length with a TeX unit.
+///
+/// A [`Dimension`] is used wherever PGFPlots expects a length e.g. the width
+/// of an axis or the size of a label.
+///
+/// # Examples
+///
+/// ```
+/// use pgfplots::dimension::Dimension;
+///
+/// assert_eq!(Dimension::Cm(8.0).to_string(), "8cm");
+/// assert_eq!(Dimension::Pt(-5.0).to_string(), "-5pt");
+/// ```
+#[derive(Clone, Copy, Debug)]
+#[non_exhaustive]
+pub enum Dimension {
+    /// Centimeters.
+    Cm(f64),
+    /// Millimeters.
+    Mm(f64),
+    /// TeX points (1in = 72.27pt).
+    Pt(f64),
+    /// Inches.
+    In(f64),
+    /// Height of an "x" in the current font.
+    Ex(f64),
+    /// Width of an "M" in the current font.
+    Em(f64),
+    /// Fraction of the width of the text of the document e.g. `0.8` for 80%
+    /// of `\textwidth`. This is mostly useful when the picture is embedded in
+    /// a larger document instead of compiled standalone.
+    TextWidth(f64),
+}
+
+impl Dimension {
+    /// Return the length in TeX points (1in = 72.27pt, 1cm ≈ 28.45pt). Returns
+    /// [`None`] for units that depend on the current font i.e.
+    /// [`Dimension::Ex`] and [`Dimension::Em`], or on the document i.e.
+    /// [`Dimension::TextWidth`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::dimension::Dimension;
+    ///
+    /// assert_eq!(Dimension::In(2.0).as_pt(), Some(144.54));
+    /// assert_eq!(Dimension::Em(1.0).as_pt(), None);
+    /// ```
+    pub fn as_pt(&self) -> Option<f64> {
+        const PT_PER_IN: f64 = 72.27;
+        const PT_PER_CM: f64 = PT_PER_IN / 2.54;
+        match self {
+            Dimension::Cm(value) => Some(value * PT_PER_CM),
+            Dimension::Mm(value) => Some(value * PT_PER_CM / 10.0),
+            Dimension::Pt(value) => Some(*value),
+            Dimension::In(value) => Some(value * PT_PER_IN),
+            Dimension::Ex(_) | Dimension::Em(_) | Dimension::TextWidth(_) => None,
+        }
+    }
+    // Apply `f` to the value, keeping the unit.
+    fn map<F: FnOnce(f64) -> f64>(self, f: F) -> Self {
+        match self {
+            Dimension::Cm(value) => Dimension::Cm(f(value)),
+            Dimension::Mm(value) => Dimension::Mm(f(value)),
+            Dimension::Pt(value) => Dimension::Pt(f(value)),
+            Dimension::In(value) => Dimension::In(f(value)),
+            Dimension::Ex(value) => Dimension::Ex(f(value)),
+            Dimension::Em(value) => Dimension::Em(f(value)),
+            Dimension::TextWidth(value) => Dimension::TextWidth(f(value)),
+        }
+    }
+}
+
+impl fmt::Display for Dimension {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Dimension::Cm(value) => write!(f, "{value}cm"),
+            Dimension::Mm(value) => write!(f, "{value}mm"),
+            Dimension::Pt(value) => write!(f, "{value}pt"),
+            Dimension::In(value) => write!(f, "{value}in"),
+            Dimension::Ex(value) => write!(f, "{value}ex"),
+            Dimension::Em(value) => write!(f, "{value}em"),
+            Dimension::TextWidth(value) => write!(f, "{value}\\textwidth"),
+        }
+    }
+}
+
+impl From<f64> for Dimension {
+    /// Conversion from a value in centimeters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::dimension::Dimension;
+    ///
+    /// let width: Dimension = 8.0.into();
+    /// assert_eq!(width.to_string(), "8cm");
+    /// ```
+    fn from(value: f64) -> Self {
+        Dimension::Cm(value)
+    }
+}
+
+impl Div<f64> for Dimension {
+    type Output = Self;
+
+    fn div(self, rhs: f64) -> Self::Output {
+        self.map(|value| value / rhs)
+    }
+}
+
+impl Mul<f64> for Dimension {
+    type Output = Self;
+
+    fn mul(self, rhs: f64) -> Self::Output {
+        self.map(|value| value * rhs)
+    }
+}
+
+impl Neg for Dimension {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        self.map(|value| -value)
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,76 @@
+use super::*;
+
+#[test]
+fn dimension_to_string() {
+    assert_eq!(Dimension::Cm(8.0).to_string(), String::from("8cm"));
+    assert_eq!(Dimension::Mm(2.5).to_string(), String::from("2.5mm"));
+    assert_eq!(Dimension::Pt(12.0).to_string(), String::from("12pt"));
+    assert_eq!(Dimension::Pt(-5.0).to_string(), String::from("-5pt"));
+    assert_eq!(Dimension::In(1.0).to_string(), String::from("1in"));
+    assert_eq!(Dimension::Ex(0.5).to_string(), String::from("0.5ex"));
+    assert_eq!(Dimension::Em(-1.0).to_string(), String::from("-1em"));
+    assert_eq!(
+        Dimension::TextWidth(0.8).to_string(),
+        String::from("0.8\\textwidth")
+    );
+}
+
+#[test]
+fn dimension_from_f64() {
+    let dimension: Dimension = 8.0.into();
+    assert!(matches!(dimension, Dimension::Cm(value) if value == 8.0));
+    assert_eq!(Dimension::from(-2.5).to_string(), String::from("-2.5cm"));
+}
+
+#[test]
+fn dimension_as_pt() {
+    let close = |a: Option<f64>, b: f64| (a.unwrap() - b).abs() < 1e-9;
+    assert!(close(Dimension::Pt(12.0).as_pt(), 12.0));
+    assert!(close(Dimension::In(1.0).as_pt(), 72.27));
+    assert!(close(Dimension::Cm(2.54).as_pt(), 72.27));
+    assert!(close(Dimension::Cm(1.0).as_pt(), 72.27 / 2.54));
+    assert!(close(Dimension::Mm(25.4).as_pt(), 72.27));
+    assert_eq!(Dimension::Ex(1.0).as_pt(), None);
+    assert_eq!(Dimension::Em(1.0).as_pt(), None);
+    assert_eq!(Dimension::TextWidth(1.0).as_pt(), None);
+}
+
+#[test]
+fn dimension_mul() {
+    assert_eq!((Dimension::Cm(8.0) * 2.0).to_string(), String::from("16cm"));
+    assert_eq!(
+        (Dimension::Em(1.5) * -2.0).to_string(),
+        String::from("-3em")
+    );
+    assert_eq!(
+        (Dimension::TextWidth(0.8) * 0.5).to_string(),
+        String::from("0.4\\textwidth")
+    );
+}
+
+#[test]
+fn dimension_neg() {
+    assert_eq!((-Dimension::Pt(5.0)).to_string(), String::from("-5pt"));
+    assert_eq!((-Dimension::Ex(-1.0)).to_string(), String::from("1ex"));
+}
+
+#[test]
+fn dimension_div() {
+    assert_eq!((Dimension::Cm(8.0) / 2.0).to_string(), String::from("4cm"));
+    assert_eq!(
+        (Dimension::Mm(3.0) / 2.0).to_string(),
+        String::from("1.5mm")
+    );
+    assert_eq!(
+        (Dimension::Pt(12.0) / -4.0).to_string(),
+        String::from("-3pt")
+    );
+    assert_eq!(
+        (Dimension::In(1.0) / 4.0).to_string(),
+        String::from("0.25in")
+    );
+    assert_eq!(
+        (Dimension::Ex(3.0) / 2.0).to_string(),
+        String::from("1.5ex")
+    );
+}
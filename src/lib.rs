@@ -45,7 +45,10 @@
 #[allow(unused_imports)]
 use crate::axis::{plot::PlotKey, AxisKey};
 
-use crate::axis::{plot::Plot2D, Axis};
+use crate::axis::{
+    plot::{color::Color, Plot2D},
+    Axis,
+};
 use rand::distributions::{Alphanumeric, DistString};
 use std::fmt;
 use std::io::Write;
@@ -56,6 +59,8 @@ use thiserror::Error;
 
 /// Axis environment inside a [`Picture`].
 pub mod axis;
+/// Lengths used to control the size of elements in a [`Picture`].
+pub mod dimension;
 
 /// Engine to compile a [`Picture`] into a PDF.
 #[derive(Clone, Copy, Debug)]
@@ -63,11 +68,58 @@ pub mod axis;
 pub enum Engine {
     /// `Pdflatex` engine (requires `pdflatex` to be installed).
     PdfLatex,
+    /// `LuaLaTeX` engine (requires `lualatex` to be installed).
+    LuaLatex,
+    /// `XeLaTeX` engine (requires `xelatex` to be installed).
+    XeLatex,
     #[cfg(feature = "tectonic")]
     /// `Tectonic` engine (does not require any external software).
     Tectonic,
 }
 
+impl Engine {
+    /// Return the first engine installed on the system, probing in order for
+    /// `pdflatex`, `lualatex`, and `xelatex` in the `PATH`. If none of these
+    /// is found, return the Tectonic engine when the `tectonic` feature is
+    /// enabled, or [`None`] otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use pgfplots::ShowPdfError;
+    /// # fn main() -> Result<(), ShowPdfError> {
+    /// use pgfplots::{Engine, Picture};
+    ///
+    /// if let Some(engine) = Engine::detect() {
+    ///     Picture::new().show_pdf(engine)?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn detect() -> Option<Engine> {
+        let external = [
+            (Engine::PdfLatex, "pdflatex"),
+            (Engine::LuaLatex, "lualatex"),
+            (Engine::XeLatex, "xelatex"),
+        ];
+        for (engine, executable) in external {
+            let status = Command::new(executable)
+                .stdout(Stdio::null())
+                .stderr(Stdio::null())
+                .arg("--version")
+                .status();
+            if matches!(status, Ok(status) if status.success()) {
+                return Some(engine);
+            }
+        }
+
+        #[cfg(feature = "tectonic")]
+        return Some(Engine::Tectonic);
+        #[cfg(not(feature = "tectonic"))]
+        None
+    }
+}
+
 /// The error type returned when a [`Picture`] fails to compile into a PDF.
 #[derive(Debug, Error)]
 pub enum CompileError {
@@ -77,6 +129,14 @@ pub enum CompileError {
     /// Compilation was executed but returned a non-zero exit code.
     #[error("compilation failed with status {status}")]
     BadExitCode { status: ExitStatus },
+    /// The jobname is empty or contains characters other than ASCII
+    /// alphanumeric characters, `-`, `_`, or `.` (e.g. whitespace or path
+    /// separators).
+    #[error("invalid jobname `{jobname}`")]
+    InvalidJobname { jobname: String },
+    /// The dimensions of the picture were not found after compilation.
+    #[error("missing picture dimensions")]
+    MissingDimensions,
     #[cfg(feature = "tectonic")]
     /// Tectonic error.
     #[error("tectonic error")]
@@ -106,29 +166,77 @@ pub enum PictureKey {
     /// Custom key-value pairs that have not been implemented. These will be
     /// appended verbatim to the options of the [`Picture`].
     Custom(String),
+    /// Exclude everything to the left of the axis (e.g. the *y* tick labels
+    /// and *y* label) from the bounding box of the picture. This requires the
+    /// picture to contain exactly one axis, and the excluded elements are
+    /// still drawn (they may be cropped in a standalone document).
+    TrimAxisLeft,
+    /// Exclude everything to the right of the axis (e.g. a colorbar) from the
+    /// bounding box of the picture. This requires the picture to contain
+    /// exactly one axis, and the excluded elements are still drawn (they may
+    /// be cropped in a standalone document).
+    TrimAxisRight,
+    /// Rotate the whole picture counterclockwise by the given angle in
+    /// degrees e.g. `90.0` for portrait placement. The bounding box of the
+    /// picture is computed after the rotation i.e. it is the box enclosing the
+    /// rotated picture.
+    Rotate(f64),
 }
 
 impl fmt::Display for PictureKey {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             PictureKey::Custom(key) => write!(f, "{key}"),
+            PictureKey::TrimAxisLeft => write!(f, "trim axis left"),
+            PictureKey::TrimAxisRight => write!(f, "trim axis right"),
+            PictureKey::Rotate(value) => write!(f, "rotate={value}"),
         }
     }
 }
 
+/// Element inside a [`Picture`] environment.
+///
+/// Elements are written in order, which allows mixing axis environments with
+/// raw Ti*k*Z code e.g. arrows or nodes that annotate the axes.
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub enum PictureElement {
+    /// Axis environment.
+    Axis(Axis),
+    /// Raw Ti*k*Z code. This will be written verbatim inside the [`Picture`]
+    /// environment.
+    Raw(String),
+}
+
+impl fmt::Display for PictureElement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PictureElement::Axis(axis) => write!(f, "{axis}"),
+            PictureElement::Raw(code) => write!(f, "{code}"),
+        }
+    }
+}
+
+impl From<Axis> for PictureElement {
+    fn from(axis: Axis) -> Self {
+        PictureElement::Axis(axis)
+    }
+}
+
 /// Picture environment.
 ///
 /// Creating a [`Picture`] is equivalent to the Ti*k*Z graphics environment:
 ///
 /// ```text
 /// \begin{tikzpicture}[PictureKeys]
-///     % axis environments
+///     % axis environments and raw TikZ code
 /// \end{tikzpicture}
 /// ```
 #[derive(Clone, Debug, Default)]
 pub struct Picture {
     keys: Vec<PictureKey>,
-    pub axes: Vec<Axis>,
+    preamble: Vec<String>,
+    pub elements: Vec<PictureElement>,
 }
 
 impl fmt::Display for Picture {
@@ -145,8 +253,8 @@ impl fmt::Display for Picture {
         }
         writeln!(f)?;
 
-        for axis in self.axes.iter() {
-            writeln!(f, "{axis}")?;
+        for element in self.elements.iter() {
+            writeln!(f, "{element}")?;
         }
 
         write!(f, "\\end{{tikzpicture}}")?;
@@ -159,7 +267,8 @@ impl From<Axis> for Picture {
     fn from(axis: Axis) -> Self {
         Self {
             keys: Vec::new(),
-            axes: vec![axis],
+            preamble: Vec::new(),
+            elements: vec![PictureElement::Axis(axis)],
         }
     }
 }
@@ -181,6 +290,23 @@ impl Picture {
     pub fn new() -> Self {
         Default::default()
     }
+    /// Create a new, empty picture environment with space for at least
+    /// `capacity` elements (e.g. axes) without reallocating.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::Picture;
+    ///
+    /// let picture = Picture::with_capacity(4);
+    /// assert!(picture.elements.capacity() >= 4);
+    /// ```
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            elements: Vec::with_capacity(capacity),
+            ..Default::default()
+        }
+    }
     /// Add a key to control the appearance of the picture. This will overwrite
     /// any previous mutually exclusive key.
     ///
@@ -195,13 +321,245 @@ impl Picture {
     pub fn add_key(&mut self, key: PictureKey) {
         match key {
             PictureKey::Custom(_) => (),
-            // If/whenever another variant is added, handle it the same way as
-            // Axis::add_key and Plot2D::add_key
+            _ => {
+                if let Some(index) = self
+                    .keys
+                    .iter()
+                    .position(|k| std::mem::discriminant(k) == std::mem::discriminant(&key))
+                {
+                    self.keys.remove(index);
+                }
+            }
         }
         self.keys.push(key);
     }
+    /// Tighten the bounding box of the picture horizontally to the axis lines
+    /// i.e. the space taken by e.g. tick labels and axis labels on the sides
+    /// is removed. This adds both [`PictureKey::TrimAxisLeft`] and
+    /// [`PictureKey::TrimAxisRight`].
+    ///
+    /// # Note
+    ///
+    /// This only works for pictures with a single axis, and it does not trim
+    /// vertically. The trimmed elements are drawn outside of the bounding box,
+    /// so they are cut from standalone documents (e.g. [`Picture::to_pdf`]).
+    /// This is mostly useful to align pictures that are embedded into another
+    /// document.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::{axis::Axis, Picture};
+    ///
+    /// let mut picture = Picture::from(Axis::new());
+    /// picture.crop();
+    /// ```
+    pub fn crop(&mut self) {
+        self.add_key(PictureKey::TrimAxisLeft);
+        self.add_key(PictureKey::TrimAxisRight);
+    }
+    /// Append an axis environment after all the previously added elements,
+    /// and return a mutable reference to it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::{axis::Axis, Picture};
+    ///
+    /// let mut picture = Picture::new();
+    /// let axis = picture.push_axis(Axis::new());
+    /// axis.set_title("Title");
+    /// ```
+    pub fn push_axis(&mut self, axis: Axis) -> &mut Axis {
+        self.elements.push(PictureElement::Axis(axis));
+        match self.elements.last_mut() {
+            Some(PictureElement::Axis(axis)) => axis,
+            _ => unreachable!("the last element is the pushed axis"),
+        }
+    }
+    /// Append raw Ti*k*Z code to the picture environment. This is written
+    /// verbatim after all the previously added elements, which allows e.g.
+    /// drawing arrows or nodes in between axis environments.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::{axis::Axis, Picture};
+    ///
+    /// let mut picture = Picture::from(Axis::new());
+    /// picture.append_raw("\\draw[->] (0,0) -- (1,1);");
+    /// picture.elements.push(Axis::new().into());
+    /// ```
+    pub fn append_raw<S: Into<String>>(&mut self, code: S) {
+        self.elements.push(PictureElement::Raw(code.into()));
+    }
+    /// Return an iterator over the axis environments of the picture. Raw
+    /// Ti*k*Z elements are skipped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::{axis::Axis, Picture};
+    ///
+    /// let mut picture = Picture::from(Axis::new());
+    /// picture.append_raw("\\node at (0,0) {A};");
+    ///
+    /// assert_eq!(picture.axes().count(), 1);
+    /// ```
+    pub fn axes(&self) -> impl Iterator<Item = &Axis> {
+        self.elements.iter().filter_map(|element| match element {
+            PictureElement::Axis(axis) => Some(axis),
+            PictureElement::Raw(_) => None,
+        })
+    }
+    /// Return an iterator that allows modifying the axis environments of the
+    /// picture. Raw Ti*k*Z elements are skipped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::{axis::Axis, Picture};
+    ///
+    /// let mut picture = Picture::from(Axis::new());
+    /// for axis in picture.axes_mut() {
+    ///     axis.set_title("Same title");
+    /// }
+    /// ```
+    pub fn axes_mut(&mut self) -> impl Iterator<Item = &mut Axis> {
+        self.elements
+            .iter_mut()
+            .filter_map(|element| match element {
+                PictureElement::Axis(axis) => Some(axis),
+                PictureElement::Raw(_) => None,
+            })
+    }
+    /// Return the total number of coordinates in all the plots of all the
+    /// axis environments of the picture (see [`Axis::num_coordinates`]).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::{axis::plot::Plot2D, Picture};
+    ///
+    /// let plot = Plot2D::from_xy(&[1.0, 2.0], &[3.0, 4.0]).unwrap();
+    /// let picture = Picture::from(plot);
+    /// assert_eq!(picture.num_coordinates(), 2);
+    /// ```
+    pub fn num_coordinates(&self) -> usize {
+        self.axes().map(Axis::num_coordinates).sum()
+    }
+    /// Add a line to the preamble of the standalone document generated by
+    /// [`Picture::standalone_string`] (and used by e.g. [`Picture::to_pdf`]).
+    /// Lines are written in order after `\usepackage{pgfplots}`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::Picture;
+    ///
+    /// let mut picture = Picture::new();
+    /// picture.add_preamble("\\usepackage{siunitx}");
+    /// picture.add_preamble("\\pgfplotsset{compat=1.18}");
+    /// ```
+    pub fn add_preamble<S: Into<String>>(&mut self, line: S) {
+        self.preamble.push(line.into());
+    }
+    /// Append the elements (e.g. axes) of `other` after the elements of the
+    /// picture. The keys of `other` are added with [`Picture::add_key`] i.e.
+    /// they overwrite any mutually exclusive key of the picture. The preamble
+    /// lines of `other` that are not already in the picture are appended.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::{axis::Axis, Picture};
+    ///
+    /// let mut picture = Picture::from(Axis::new());
+    /// picture.merge(Picture::from(Axis::new()));
+    /// assert_eq!(picture.axes().count(), 2);
+    /// ```
+    pub fn merge(&mut self, other: Picture) {
+        for key in other.keys {
+            self.add_key(key);
+        }
+        for line in other.preamble {
+            if !self.preamble.contains(&line) {
+                self.preamble.push(line);
+            }
+        }
+        self.elements.extend(other.elements);
+    }
+    /// Remove all the keys, elements, and preamble lines of the picture. The
+    /// allocated memory is kept to be reused.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::{axis::Axis, Picture};
+    ///
+    /// let mut picture = Picture::from(Axis::new());
+    /// picture.clear();
+    /// assert!(picture.elements.is_empty());
+    /// ```
+    pub fn clear(&mut self) {
+        self.keys.clear();
+        self.preamble.clear();
+        self.elements.clear();
+    }
+    /// Return the picture environment as a compact [`String`]. Unlike the
+    /// [`Display`](fmt::Display) implementation, keys and coordinates are
+    /// written on a single line without indentation. The output is
+    /// deterministic, which makes it convenient to compare (e.g. diff) the code
+    /// generated for different pictures.
+    ///
+    /// Lines that contain a `%` comment are never joined with the next line.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::{axis::{Axis, AxisKey}, Picture};
+    ///
+    /// let mut axis = Axis::new();
+    /// axis.add_key(AxisKey::Title(String::from("Title")));
+    /// axis.add_key(AxisKey::XLabel(String::from("x")));
+    ///
+    /// assert_eq!(
+    ///     Picture::from(axis).to_string_compact(),
+    ///     "\\begin{tikzpicture}\n\\begin{axis}[title={Title},xlabel={x}]\n\\end{axis}\n\\end{tikzpicture}"
+    /// );
+    /// ```
+    pub fn to_string_compact(&self) -> String {
+        let mut compact = String::new();
+        // Separator written before the next line.
+        let mut separator = "";
+        for line in self.to_string().lines().map(str::trim) {
+            if line.is_empty() {
+                continue;
+            }
+            if line.starts_with(']') && compact.ends_with(',') {
+                compact.pop();
+            } else if (line.starts_with('(') || line.starts_with('}')) && compact.ends_with(')') {
+                separator = " ";
+            }
+            compact.push_str(separator);
+            compact.push_str(&line.replace('\t', " "));
+
+            separator = if !line.contains('%')
+                && (line.ends_with('[') || line.ends_with(',') || line.ends_with('{'))
+            {
+                ""
+            } else {
+                "\n"
+            };
+        }
+
+        compact
+    }
     /// Return a [`String`] with valid LaTeX code that generates a standalone
-    /// PDF with the picture environment.
+    /// PDF with the picture environment. The preamble automatically loads the
+    /// `xcolor` options and color definitions required by the colors used in
+    /// the axes (e.g. colors created with [`Color::from_rgb`]), and the
+    /// `pgfplots` libraries required by the plots.
     ///
     /// # Note
     ///
@@ -226,11 +584,120 @@ impl Picture {
     /// picture.standalone_string());
     /// ```
     pub fn standalone_string(&self) -> String {
-        String::from("\\documentclass{standalone}\n")
-            + "\\usepackage{pgfplots}\n"
-            + "\\begin{document}\n"
-            + &self.to_string()
-            + "\n\\end{document}"
+        self.standalone_string_with_preamble("")
+    }
+    /// Return a [`String`] with valid LaTeX code that generates a standalone
+    /// PDF with the picture environment. The `extra_preamble` is inserted
+    /// after the preamble of the picture (see [`Picture::add_preamble`]) and
+    /// before `\begin{document}`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::Picture;
+    ///
+    /// let picture = Picture::new();
+    /// assert_eq!(
+    /// r#"\documentclass{standalone}
+    /// \usepackage{pgfplots}
+    /// \usepackage{siunitx}
+    /// \begin{document}
+    /// \begin{tikzpicture}
+    /// \end{tikzpicture}
+    /// \end{document}"#,
+    /// picture.standalone_string_with_preamble("\\usepackage{siunitx}"));
+    /// ```
+    pub fn standalone_string_with_preamble(&self, extra_preamble: &str) -> String {
+        self.standalone_document(extra_preamble, &self.to_string())
+    }
+    /// Return the picture environment to be embedded in an existing LaTeX
+    /// document i.e. only the code from `\begin{tikzpicture}` to
+    /// `\end{tikzpicture}`. This is the same as the [`Display`](fmt::Display)
+    /// implementation.
+    ///
+    /// The preamble of the host document must load `pgfplots` and everything
+    /// else the picture needs (e.g. color definitions); see
+    /// [`Picture::required_packages`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::Picture;
+    ///
+    /// let picture = Picture::new();
+    /// assert_eq!(
+    ///     picture.embeddable_string(),
+    ///     "\\begin{tikzpicture}\n\\end{tikzpicture}"
+    /// );
+    /// ```
+    pub fn embeddable_string(&self) -> String {
+        self.to_string()
+    }
+    /// Return the lines that the preamble of a host document must include to
+    /// compile the [`Picture::embeddable_string`], in order. These are the
+    /// `xcolor` options and color definitions required by the colors used in
+    /// the axes, `\usepackage{pgfplots}` and the libraries required by the
+    /// plots (e.g. `fillbetween`), and the lines added with
+    /// [`Picture::add_preamble`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::Picture;
+    ///
+    /// let mut picture = Picture::new();
+    /// picture.add_preamble("\\usepackage{siunitx}");
+    /// assert_eq!(
+    ///     picture.required_packages(),
+    ///     ["\\usepackage{pgfplots}", "\\usepackage{siunitx}"]
+    /// );
+    /// ```
+    pub fn required_packages(&self) -> Vec<String> {
+        let colors: Vec<&Color> = self.axes().flat_map(Axis::colors).collect();
+
+        let mut lines = Vec::new();
+        // Package options of `xcolor` have to be set before `pgfplots` loads
+        // it. Otherwise there is an option clash.
+        if colors.iter().any(|color| color.requires_svgnames()) {
+            lines.push(String::from("\\usepackage[svgnames]{xcolor}"));
+        }
+        lines.push(String::from("\\usepackage{pgfplots}"));
+        if self.axes().any(Axis::requires_fillbetween) {
+            lines.push(String::from("\\usepgfplotslibrary{fillbetween}"));
+        }
+
+        for definition in colors.iter().filter_map(|color| color.definition()) {
+            if !lines.contains(&definition) {
+                lines.push(definition);
+            }
+        }
+        lines.extend(
+            self.preamble
+                .iter()
+                .filter(|line| !line.is_empty())
+                .cloned(),
+        );
+
+        lines
+    }
+    // Return a standalone document with the preamble of the picture (followed
+    // by the `extra_preamble`) and the given `body`.
+    fn standalone_document(&self, extra_preamble: &str, body: &str) -> String {
+        let mut preamble = String::from("\\documentclass{standalone}\n");
+        for line in self
+            .required_packages()
+            .iter()
+            .map(String::as_str)
+            .chain(std::iter::once(extra_preamble))
+            .filter(|line| !line.is_empty())
+        {
+            preamble += line;
+            if !line.ends_with('\n') {
+                preamble += "\n";
+            }
+        }
+
+        preamble + "\\begin{document}\n" + body + "\n\\end{document}"
     }
     /// Compile the picture environment into a standalone PDF document. This
     /// will create the file `jobname.pdf` in the specified `working_dir`
@@ -238,6 +705,10 @@ impl Picture {
     /// `.aux` files). Return a [`Result`] with the path to the generated PDF
     /// file or a [`CompileError`].
     ///
+    /// The `jobname` can only contain ASCII alphanumeric characters, `-`, `_`,
+    /// and `.`; otherwise a [`CompileError::InvalidJobname`] is returned
+    /// without attempting to compile the picture.
+    ///
     /// # Examples
     ///
     // Example is `no_run` because `std::env::temp_dir` causes the test to fail
@@ -266,66 +737,127 @@ impl Picture {
         // str instead of OsStr because of Tectonic's `tex_input_file`
         S: AsRef<str>,
     {
-        // Copy the tex code to a temporary file instead of passing it directly
-        // to the engine via e.g. stdin. This avoids the "Argument list too
-        // long" error when there are e.g. too many points in a plot.
-        let mut tex_file = NamedTempFile::new()?;
-        tex_file.write_all(self.standalone_string().as_bytes())?;
-
-        match engine {
-            Engine::PdfLatex => {
-                let status = Command::new("pdflatex")
-                    .current_dir(working_dir.as_ref())
-                    .stdout(Stdio::null())
-                    .stderr(Stdio::null())
-                    .arg("-interaction=batchmode")
-                    .arg("-halt-on-error")
-                    .arg(String::from("-jobname=") + jobname.as_ref())
-                    .arg(tex_file.path())
-                    .status()?;
-
-                if !status.success() {
-                    return Err(CompileError::BadExitCode { status });
-                }
-            }
-            #[cfg(feature = "tectonic")]
-            // Modified from `tectonic::latex_to_pdf` to generate the files
-            // instead of just returning the bytes.
-            Engine::Tectonic => {
-                let mut status = tectonic::status::NoopStatusBackend::default();
-
-                let auto_create_config_file = false;
-                let config = tectonic::ctry!(tectonic::config::PersistentConfig::open(auto_create_config_file);
-                       "failed to open the default configuration file");
-
-                let only_cached = false;
-                let bundle = tectonic::ctry!(config.default_bundle(only_cached, &mut status);
-                       "failed to load the default resource bundle");
-
-                let format_cache_path = tectonic::ctry!(config.format_cache_path();
-                                  "failed to set up the format cache");
-
-                let mut sb = tectonic::driver::ProcessingSessionBuilder::default();
-                sb.bundle(bundle)
-                    .primary_input_path(tex_file.path())
-                    .tex_input_name(jobname.as_ref())
-                    .format_name("latex")
-                    .format_cache_path(format_cache_path)
-                    // Just to keep the behaviour consistent with `pdflatex`
-                    .keep_logs(true)
-                    .keep_intermediates(true)
-                    .print_stdout(false)
-                    .output_format(tectonic::driver::OutputFormat::Pdf)
-                    .output_dir(working_dir.as_ref());
-
-                let mut sess = tectonic::ctry!(sb.create(&mut status); "failed to initialize the LaTeX processing session");
-                tectonic::ctry!(sess.run(&mut status); "the LaTeX engine failed");
-            }
-        }
+        validate_jobname(jobname.as_ref())?;
+
+        compile(
+            &self.standalone_string(),
+            working_dir.as_ref(),
+            jobname.as_ref(),
+            engine,
+        )?;
+
         Ok(working_dir
             .as_ref()
             .join(String::from(jobname.as_ref()) + ".pdf"))
     }
+    /// Compile the picture environment into a standalone PDF document, and
+    /// return the bytes of the PDF file. The `Engine::Tectonic` variant does
+    /// all the processing in memory; other engines compile the picture in a
+    /// temporary directory which is removed afterwards.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use pgfplots::CompileError;
+    /// # fn main() -> Result<(), CompileError> {
+    /// use pgfplots::{Engine, Picture};
+    ///
+    /// let picture = Picture::new();
+    /// let pdf = picture.to_pdf_bytes(Engine::PdfLatex)?;
+    ///
+    /// assert!(pdf.starts_with(b"%PDF"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn to_pdf_bytes(&self, engine: Engine) -> Result<Vec<u8>, CompileError> {
+        #[cfg(feature = "tectonic")]
+        if matches!(engine, Engine::Tectonic) {
+            return Ok(tectonic::latex_to_pdf(self.standalone_string())?);
+        }
+
+        let working_dir = tempfile::tempdir()?;
+        let pdf_path = self.to_pdf(working_dir.path(), "pgfplots", engine)?;
+        Ok(std::fs::read(pdf_path)?)
+    }
+    /// Compile the picture environment into a standalone PDF document, and
+    /// write the bytes of the PDF file into `writer` e.g. the body of an HTTP
+    /// response. The same as [`Picture::to_pdf_bytes`], the picture is compiled
+    /// in memory (Tectonic) or in a temporary directory which is removed
+    /// afterwards.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use pgfplots::CompileError;
+    /// # fn main() -> Result<(), CompileError> {
+    /// use pgfplots::{Engine, Picture};
+    ///
+    /// let picture = Picture::new();
+    /// let mut pdf = Vec::new();
+    /// picture.write_pdf_to(&mut pdf, Engine::PdfLatex)?;
+    ///
+    /// assert!(pdf.starts_with(b"%PDF"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn write_pdf_to<W: std::io::Write>(
+        &self,
+        writer: &mut W,
+        engine: Engine,
+    ) -> Result<(), CompileError> {
+        #[cfg(feature = "tectonic")]
+        if matches!(engine, Engine::Tectonic) {
+            writer.write_all(&tectonic::latex_to_pdf(self.standalone_string())?)?;
+            return Ok(());
+        }
+
+        let working_dir = tempfile::tempdir()?;
+        let pdf_path = self.to_pdf(working_dir.path(), "pgfplots", engine)?;
+        std::io::copy(&mut std::fs::File::open(pdf_path)?, writer)?;
+        Ok(())
+    }
+    /// Return the natural `(width, height)` of the picture environment in TeX
+    /// points (1in = 72.27pt). This requires compiling the picture with the
+    /// given `engine` in a temporary directory.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use pgfplots::CompileError;
+    /// # fn main() -> Result<(), CompileError> {
+    /// use pgfplots::{axis::Axis, Engine, Picture};
+    ///
+    /// let picture = Picture::from(Axis::new());
+    /// let (width, height) = picture.dimensions(Engine::PdfLatex)?;
+    ///
+    /// assert!(width > height);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn dimensions(&self, engine: Engine) -> Result<(f64, f64), CompileError> {
+        // Measure the picture in TeX itself and write the size to the log
+        // file. This works the same for every engine, and it doesn't require
+        // parsing the (usually compressed) PDF.
+        let body = String::from("\\sbox0{")
+            + &self.to_string()
+            + "}\n"
+            + "\\typeout{"
+            + DIMENSIONS_MARKER
+            + "\\the\\wd0,\\the\\ht0,\\the\\dp0}\n"
+            + "\\usebox0";
+
+        let working_dir = tempfile::tempdir()?;
+        let jobname = "pgfplots";
+        compile(
+            &self.standalone_document("", &body),
+            working_dir.path(),
+            jobname,
+            engine,
+        )?;
+
+        let log = std::fs::read(working_dir.path().join(String::from(jobname) + ".log"))?;
+        parse_dimensions(&String::from_utf8_lossy(&log)).ok_or(CompileError::MissingDimensions)
+    }
     /// Show the picture environment in a standalone PDF document. This will
     /// create a file in the location returned by [`std::env::temp_dir`] and
     /// open it with the default PDF viewer.
@@ -367,5 +899,120 @@ impl Picture {
     }
 }
 
+// Compile a LaTeX `document` with the given `engine`. This will create the
+// file `jobname.pdf` (and any auxiliary files) in the `working_dir`.
+fn compile(
+    document: &str,
+    working_dir: &Path,
+    // str instead of OsStr because of Tectonic's `tex_input_file`
+    jobname: &str,
+    engine: Engine,
+) -> Result<(), CompileError> {
+    // Copy the tex code to a temporary file instead of passing it directly
+    // to the engine via e.g. stdin. This avoids the "Argument list too
+    // long" error when there are e.g. too many points in a plot.
+    let mut tex_file = NamedTempFile::new()?;
+    tex_file.write_all(document.as_bytes())?;
+
+    match engine {
+        Engine::PdfLatex => run_latex("pdflatex", working_dir, jobname, tex_file.path())?,
+        Engine::LuaLatex => run_latex("lualatex", working_dir, jobname, tex_file.path())?,
+        Engine::XeLatex => run_latex("xelatex", working_dir, jobname, tex_file.path())?,
+        #[cfg(feature = "tectonic")]
+        // Modified from `tectonic::latex_to_pdf` to generate the files
+        // instead of just returning the bytes.
+        Engine::Tectonic => {
+            let mut status = tectonic::status::NoopStatusBackend::default();
+
+            let auto_create_config_file = false;
+            let config = tectonic::ctry!(tectonic::config::PersistentConfig::open(auto_create_config_file);
+                   "failed to open the default configuration file");
+
+            let only_cached = false;
+            let bundle = tectonic::ctry!(config.default_bundle(only_cached, &mut status);
+                   "failed to load the default resource bundle");
+
+            let format_cache_path = tectonic::ctry!(config.format_cache_path();
+                              "failed to set up the format cache");
+
+            let mut sb = tectonic::driver::ProcessingSessionBuilder::default();
+            sb.bundle(bundle)
+                .primary_input_path(tex_file.path())
+                .tex_input_name(jobname)
+                .format_name("latex")
+                .format_cache_path(format_cache_path)
+                // Just to keep the behaviour consistent with `pdflatex`
+                .keep_logs(true)
+                .keep_intermediates(true)
+                .print_stdout(false)
+                .output_format(tectonic::driver::OutputFormat::Pdf)
+                .output_dir(working_dir);
+
+            let mut sess = tectonic::ctry!(sb.create(&mut status); "failed to initialize the LaTeX processing session");
+            tectonic::ctry!(sess.run(&mut status); "the LaTeX engine failed");
+        }
+    }
+    Ok(())
+}
+
+// Compile the `tex_file` with an external LaTeX executable. All of them share
+// the same command line options.
+fn run_latex(
+    executable: &str,
+    working_dir: &Path,
+    jobname: &str,
+    tex_file: &Path,
+) -> Result<(), CompileError> {
+    let status = Command::new(executable)
+        .current_dir(working_dir)
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .arg("-interaction=batchmode")
+        .arg("-halt-on-error")
+        .arg(String::from("-jobname=") + jobname)
+        .arg(tex_file)
+        .status()?;
+
+    if !status.success() {
+        return Err(CompileError::BadExitCode { status });
+    }
+    Ok(())
+}
+
+// Prefix of the line written to the log file by `Picture::dimensions`.
+const DIMENSIONS_MARKER: &str = "pgfplots dimensions:";
+
+// Parse the `(width, height)` in points from the line written to the log file
+// by `Picture::dimensions` i.e. `{DIMENSIONS_MARKER}{wd}pt,{ht}pt,{dp}pt`.
+fn parse_dimensions(log: &str) -> Option<(f64, f64)> {
+    let line = log
+        .lines()
+        .find_map(|line| line.strip_prefix(DIMENSIONS_MARKER))?;
+    let values = line
+        .split(',')
+        .map(|value| value.trim().strip_suffix("pt")?.parse().ok())
+        .collect::<Option<Vec<f64>>>()?;
+    match values[..] {
+        [width, height, depth] => Some((width, height + depth)),
+        _ => None,
+    }
+}
+
+// Jobnames are used both as an argument to the engine and as the file name of
+// the output files. Restrict them to characters that are safe in both cases,
+// which also prevents writing files outside of the working directory.
+fn validate_jobname(jobname: &str) -> Result<(), CompileError> {
+    if jobname.is_empty()
+        || !jobname
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'))
+    {
+        return Err(CompileError::InvalidJobname {
+            jobname: String::from(jobname),
+        });
+    }
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests;
@@ -13,9 +13,46 @@ fn picture_keys_tested() {
     let picture_key = PictureKey::Custom(String::from(""));
     match picture_key {
         PictureKey::Custom(_) => (),
+        PictureKey::TrimAxisLeft => (),
+        PictureKey::TrimAxisRight => (),
+        PictureKey::Rotate(_) => (),
     }
 }
 
+#[test]
+fn picture_key_trim_axis_left_to_string() {
+    assert_eq!(
+        PictureKey::TrimAxisLeft.to_string(),
+        String::from("trim axis left")
+    );
+}
+
+#[test]
+fn picture_key_trim_axis_right_to_string() {
+    assert_eq!(
+        PictureKey::TrimAxisRight.to_string(),
+        String::from("trim axis right")
+    );
+}
+
+#[test]
+fn picture_key_rotate_to_string() {
+    assert_eq!(
+        PictureKey::Rotate(90.0).to_string(),
+        String::from("rotate=90")
+    );
+    assert_eq!(
+        PictureKey::Rotate(-22.5).to_string(),
+        String::from("rotate=-22.5")
+    );
+
+    let mut picture = Picture::new();
+    picture.add_key(PictureKey::Rotate(45.0));
+    picture.add_key(PictureKey::Rotate(90.0));
+    assert_eq!(picture.keys.len(), 1);
+    assert_eq!(picture.keys[0].to_string(), String::from("rotate=90"));
+}
+
 #[test]
 fn picture_key_custom_to_string() {
     assert_eq!(
@@ -27,8 +64,18 @@ fn picture_key_custom_to_string() {
 #[test]
 fn picture_new() {
     let picture = Picture::new();
-    assert!(picture.axes.is_empty());
+    assert!(picture.elements.is_empty());
+    assert!(picture.keys.is_empty());
+    assert!(picture.preamble.is_empty());
+}
+
+#[test]
+fn picture_with_capacity() {
+    let picture = Picture::with_capacity(3);
+    assert!(picture.elements.is_empty());
+    assert!(picture.elements.capacity() >= 3);
     assert!(picture.keys.is_empty());
+    assert!(picture.preamble.is_empty());
 }
 
 #[test]
@@ -42,6 +89,131 @@ fn picture_add_key() {
     assert_eq!(picture.keys.len(), 2);
     assert_eq!(picture.keys[0].to_string(), String::from("random"));
     assert_eq!(picture.keys[1].to_string(), String::from("random"));
+
+    picture.add_key(PictureKey::TrimAxisLeft);
+    assert_eq!(picture.keys.len(), 3);
+    picture.add_key(PictureKey::TrimAxisRight);
+    assert_eq!(picture.keys.len(), 4);
+    picture.add_key(PictureKey::TrimAxisLeft);
+    assert_eq!(picture.keys.len(), 4);
+    assert_eq!(picture.keys[0].to_string(), String::from("random"));
+    assert_eq!(picture.keys[1].to_string(), String::from("random"));
+    assert_eq!(picture.keys[2].to_string(), String::from("trim axis right"));
+    assert_eq!(picture.keys[3].to_string(), String::from("trim axis left"));
+}
+
+#[test]
+fn picture_crop() {
+    let mut picture = Picture::from(Axis::new());
+    picture.crop();
+    picture.crop();
+    assert_eq!(picture.keys.len(), 2);
+    assert!(picture
+        .to_string()
+        .starts_with("\\begin{tikzpicture}[\n\ttrim axis left,\n\ttrim axis right,\n]\n"));
+}
+
+#[test]
+fn picture_element_to_string() {
+    assert_eq!(
+        PictureElement::Axis(Axis::new()).to_string(),
+        "\\begin{axis}\n\\end{axis}"
+    );
+    assert_eq!(
+        PictureElement::Raw(String::from("\\draw (0,0) -- (1,1);")).to_string(),
+        "\\draw (0,0) -- (1,1);"
+    );
+}
+
+#[test]
+fn picture_append_raw() {
+    let mut picture = Picture::from(Axis::new());
+    picture.append_raw("\\draw (0,0) -- (1,1);");
+    picture.elements.push(Axis::new().into());
+    assert_eq!(picture.elements.len(), 3);
+    assert_eq!(picture.axes().count(), 2);
+    assert_eq!(
+        picture.to_string(),
+        "\\begin{tikzpicture}\n\\begin{axis}\n\\end{axis}\n\\draw (0,0) -- (1,1);\n\\begin{axis}\n\\end{axis}\n\\end{tikzpicture}"
+    );
+}
+
+#[test]
+fn picture_push_axis() {
+    let mut picture = Picture::new();
+    picture.append_raw("\\draw (0,0) -- (1,1);");
+    let axis = picture.push_axis(Axis::new());
+    axis.plots.push(Plot2D::new());
+
+    assert_eq!(picture.elements.len(), 2);
+    assert!(matches!(picture.elements[0], PictureElement::Raw(_)));
+    assert_eq!(picture.axes().next().unwrap().plots.len(), 1);
+}
+
+#[test]
+fn picture_num_coordinates() {
+    let mut picture = Picture::new();
+    assert_eq!(picture.num_coordinates(), 0);
+
+    let mut axis = Axis::from(Plot2D::from_xy(&[1.0, 2.0], &[1.0, 2.0]).unwrap());
+    axis.push_plot(Plot2D::from_xy(&[1.0, 2.0, 3.0], &[1.0, 2.0, 3.0]).unwrap());
+    picture.push_axis(axis);
+    picture.append_raw("\\draw (0,0) -- (1,1);");
+    picture.push_axis(Axis::from(Plot2D::from_xy(&[1.0], &[1.0]).unwrap()));
+    assert_eq!(picture.num_coordinates(), 6);
+}
+
+#[test]
+fn picture_axes_mut() {
+    let mut picture = Picture::from(Axis::new());
+    picture.append_raw("\\draw (0,0) -- (1,1);");
+    picture.elements.push(Axis::new().into());
+    for axis in picture.axes_mut() {
+        axis.plots.push(Plot2D::new());
+    }
+    assert!(picture.axes().all(|axis| axis.plots.len() == 1));
+}
+
+#[test]
+fn picture_merge() {
+    let mut picture = Picture::from(Axis::new());
+    picture.add_key(PictureKey::Custom(String::from("baseline")));
+    picture.add_preamble("\\usepackage{siunitx}");
+
+    let mut other = Picture::from(Axis::new());
+    other.append_raw("\\draw (0,0) -- (1,1);");
+    other.elements.push(Axis::new().into());
+    other.add_key(PictureKey::Custom(String::from("scale=2")));
+    other.add_preamble("\\usepackage{siunitx}");
+    other.add_preamble("\\pgfplotsset{compat=1.18}");
+
+    picture.merge(other);
+    assert_eq!(picture.elements.len(), 4);
+    assert_eq!(picture.axes().count(), 3);
+    assert!(matches!(picture.elements[2], PictureElement::Raw(_)));
+    assert_eq!(picture.keys.len(), 2);
+    assert_eq!(picture.keys[0].to_string(), String::from("baseline"));
+    assert_eq!(picture.keys[1].to_string(), String::from("scale=2"));
+    assert_eq!(
+        picture.preamble,
+        vec![
+            String::from("\\usepackage{siunitx}"),
+            String::from("\\pgfplotsset{compat=1.18}")
+        ]
+    );
+}
+
+#[test]
+fn picture_clear() {
+    let mut picture = Picture::from(Axis::new());
+    picture.add_key(PictureKey::Custom(String::from("random")));
+    picture.append_raw("\\draw (0,0) -- (1,1);");
+    picture.add_preamble("\\usepackage{siunitx}");
+
+    picture.clear();
+    assert!(picture.elements.is_empty());
+    assert!(picture.keys.is_empty());
+    assert!(picture.preamble.is_empty());
 }
 
 #[test]
@@ -58,6 +230,173 @@ fn picture_standalone_string() {
     );
 }
 
+#[test]
+fn picture_add_preamble() {
+    let mut picture = Picture::new();
+    picture.add_preamble("\\usepackage{siunitx}");
+    picture.add_preamble("\\pgfplotsset{compat=1.18}");
+    assert_eq!(picture.preamble.len(), 2);
+    assert_eq!(
+        r#"\documentclass{standalone}
+\usepackage{pgfplots}
+\usepackage{siunitx}
+\pgfplotsset{compat=1.18}
+\begin{document}
+\begin{tikzpicture}
+\end{tikzpicture}
+\end{document}"#,
+        picture.standalone_string()
+    );
+}
+
+#[test]
+fn picture_standalone_string_with_preamble() {
+    let mut picture = Picture::new();
+    assert_eq!(
+        picture.standalone_string_with_preamble(""),
+        picture.standalone_string()
+    );
+    assert_eq!(
+        r#"\documentclass{standalone}
+\usepackage{pgfplots}
+\usepackage{siunitx}
+\usetikzlibrary{arrows}
+\begin{document}
+\begin{tikzpicture}
+\end{tikzpicture}
+\end{document}"#,
+        picture
+            .standalone_string_with_preamble("\\usepackage{siunitx}\n\\usetikzlibrary{arrows}\n")
+    );
+
+    picture.add_preamble("\\pgfplotsset{compat=1.18}");
+    assert_eq!(
+        r#"\documentclass{standalone}
+\usepackage{pgfplots}
+\pgfplotsset{compat=1.18}
+\usepackage{siunitx}
+\begin{document}
+\begin{tikzpicture}
+\end{tikzpicture}
+\end{document}"#,
+        picture.standalone_string_with_preamble("\\usepackage{siunitx}")
+    );
+}
+
+#[test]
+fn picture_embeddable_string() {
+    let mut picture = Picture::from(Axis::from(Plot2D::new()));
+    picture.add_preamble("\\usepackage{siunitx}");
+    let embeddable = picture.embeddable_string();
+    assert!(!embeddable.contains("\\documentclass"));
+    assert!(!embeddable.contains("\\usepackage"));
+    assert!(!embeddable.contains("\\begin{document}"));
+    assert!(embeddable.starts_with("\\begin{tikzpicture}"));
+    assert!(embeddable.ends_with("\\end{tikzpicture}"));
+    assert!(picture.standalone_string().contains(&embeddable));
+}
+
+#[test]
+fn picture_required_packages_fillbetween() {
+    use crate::axis::plot::color::PredefinedColor;
+
+    let upper = Plot2D::from_xy(&[0.0, 1.0], &[2.0, 3.0]).unwrap();
+    let lower = Plot2D::from_xy(&[0.0, 1.0], &[1.0, 1.5]).unwrap();
+    let mut axis = Axis::new();
+    axis.plots.extend(Plot2D::band_between(
+        &upper,
+        &lower,
+        PredefinedColor::Gray.into(),
+    ));
+    let picture = Picture::from(axis);
+    assert_eq!(
+        picture.required_packages(),
+        [
+            "\\usepackage{pgfplots}",
+            "\\usepgfplotslibrary{fillbetween}"
+        ]
+    );
+    assert!(picture
+        .standalone_string()
+        .contains("\\usepackage{pgfplots}\n\\usepgfplotslibrary{fillbetween}\n"));
+}
+
+#[test]
+fn picture_required_packages() {
+    use crate::axis::plot::{MarkOption, MarkShape, Marker, PlotKey};
+
+    assert_eq!(
+        Picture::new().required_packages(),
+        ["\\usepackage{pgfplots}"]
+    );
+
+    let mut plot = Plot2D::new();
+    plot.add_key(PlotKey::Marker(Marker::new(
+        MarkShape::OFilled,
+        vec![MarkOption::Fill(Color::from_hex(0x4682B4))],
+    )));
+    plot.add_key(PlotKey::Fill(Color::named("SteelBlue")));
+    let mut picture = Picture::from(plot);
+    picture.add_preamble("\\usepackage{siunitx}");
+    picture.add_preamble("");
+    assert_eq!(
+        picture.required_packages(),
+        [
+            "\\usepackage[svgnames]{xcolor}",
+            "\\usepackage{pgfplots}",
+            "\\definecolor{rgb4682B4}{HTML}{4682B4}",
+            "\\usepackage{siunitx}",
+        ]
+    );
+}
+
+#[test]
+fn picture_standalone_string_colors() {
+    use crate::axis::{
+        plot::{MarkOption, MarkShape, Marker, PlotKey},
+        AxisKey, Colormap,
+    };
+
+    let mut plot = Plot2D::new();
+    plot.add_key(PlotKey::Marker(Marker::new(
+        MarkShape::OFilled,
+        vec![MarkOption::Fill(Color::from_hex(0x4682B4))],
+    )));
+    let mut axis = Axis::from(plot);
+    let mut picture = Picture::from(axis.clone());
+    picture.add_preamble("\\usepackage{siunitx}");
+    assert_eq!(
+        r#"\documentclass{standalone}
+\usepackage{pgfplots}
+\definecolor{rgb4682B4}{HTML}{4682B4}
+\usepackage{siunitx}
+\begin{document}
+"#
+        .to_string()
+            + &picture.to_string()
+            + "\n\\end{document}",
+        picture.standalone_string()
+    );
+
+    axis.add_key(AxisKey::DefineColormap(Colormap::custom(
+        "mymap",
+        [Color::named("SteelBlue"), Color::from_rgb(70, 130, 180)],
+    )));
+    let picture = Picture::from(axis);
+    assert_eq!(
+        r#"\documentclass{standalone}
+\usepackage[svgnames]{xcolor}
+\usepackage{pgfplots}
+\definecolor{rgb4682B4}{HTML}{4682B4}
+\begin{document}
+"#
+        .to_string()
+            + &picture.to_string()
+            + "\n\\end{document}",
+        picture.standalone_string()
+    );
+}
+
 #[test]
 fn picture_to_string() {
     let mut picture = Picture::new();
@@ -74,7 +413,7 @@ fn picture_to_string() {
 
     picture.keys.clear();
     let mut axis = Axis::new();
-    picture.axes.push(axis.clone());
+    picture.elements.push(axis.clone().into());
     assert_eq!(
         picture.to_string(),
         "\\begin{tikzpicture}\n\\begin{axis}\n\\end{axis}\n\\end{tikzpicture}"
@@ -83,6 +422,144 @@ fn picture_to_string() {
     picture.add_key(PictureKey::Custom(String::from("baseline")));
     picture.add_key(PictureKey::Custom(String::from("scale=2")));
     axis.plots.push(Plot2D::new());
-    picture.axes.push(axis.clone());
+    picture.elements.push(axis.clone().into());
     assert_eq!(picture.to_string(), "\\begin{tikzpicture}[\n\tbaseline,\n\tscale=2,\n]\n\\begin{axis}\n\\end{axis}\n\\begin{axis}\n\t\\addplot[] coordinates {\n\t};\n\\end{axis}\n\\end{tikzpicture}");
 }
+
+#[test]
+fn valid_jobname() {
+    for jobname in ["jobname", "my_figure-2.v1", "pgfplots_AbC123"] {
+        assert!(validate_jobname(jobname).is_ok());
+    }
+}
+
+#[test]
+fn invalid_jobname() {
+    for jobname in [
+        "",
+        "my figure",
+        "../jobname",
+        "dir/jobname",
+        "dir\\jobname",
+        "a\tb",
+        "ñ",
+    ] {
+        match validate_jobname(jobname) {
+            Err(CompileError::InvalidJobname { jobname: returned }) => {
+                assert_eq!(returned, jobname)
+            }
+            _ => panic!("`{jobname}` should be invalid"),
+        }
+    }
+}
+
+#[test]
+fn picture_to_pdf_invalid_jobname() {
+    let picture = Picture::new();
+    let result = picture.to_pdf(std::env::temp_dir(), "../jobname", Engine::PdfLatex);
+    assert!(matches!(
+        result,
+        Err(CompileError::InvalidJobname { jobname }) if jobname == "../jobname"
+    ));
+}
+
+#[test]
+fn parse_dimensions_from_log() {
+    let log =
+        "This is pdfTeX\n(./pgfplots.aux)\npgfplots dimensions:227.62204pt,150.5pt,0.5pt\n[1]\n";
+    assert_eq!(parse_dimensions(log), Some((227.62204, 151.0)));
+
+    assert_eq!(parse_dimensions("This is pdfTeX\n[1]\n"), None);
+    assert_eq!(parse_dimensions("pgfplots dimensions:1pt,2pt\n"), None);
+    assert_eq!(parse_dimensions("pgfplots dimensions:1pt,2cm,0pt\n"), None);
+}
+
+#[test]
+#[ignore = "requires a LaTeX installation"]
+fn engine_detect() {
+    assert!(Engine::detect().is_some());
+}
+
+#[cfg(feature = "tectonic")]
+#[test]
+fn engine_detect_tectonic() {
+    assert!(Engine::detect().is_some());
+}
+
+#[cfg(feature = "tectonic")]
+#[test]
+fn picture_dimensions() {
+    let picture = Picture::from(Axis::new());
+    let (width, height) = picture.dimensions(Engine::Tectonic).unwrap();
+    assert!(width > 0.0);
+    assert!(height > 0.0);
+}
+
+#[cfg(feature = "tectonic")]
+#[test]
+fn picture_to_pdf_bytes() {
+    let picture = Picture::from(Axis::new());
+    let pdf = picture.to_pdf_bytes(Engine::Tectonic).unwrap();
+    assert!(pdf.starts_with(b"%PDF"));
+}
+
+#[cfg(feature = "tectonic")]
+#[test]
+fn picture_write_pdf_to() {
+    let picture = Picture::from(Axis::new());
+    let mut pdf = Vec::new();
+    picture.write_pdf_to(&mut pdf, Engine::Tectonic).unwrap();
+    assert!(pdf.starts_with(b"%PDF"));
+}
+
+#[test]
+#[ignore = "requires a LaTeX installation"]
+fn picture_write_pdf_to_pdflatex() {
+    let picture = Picture::from(Axis::new());
+    let mut pdf = Vec::new();
+    picture.write_pdf_to(&mut pdf, Engine::PdfLatex).unwrap();
+    assert!(pdf.starts_with(b"%PDF"));
+}
+
+#[test]
+fn picture_to_string_compact() {
+    use crate::axis::{plot::PlotKey, AxisKey};
+
+    assert_eq!(
+        Picture::new().to_string_compact(),
+        String::from("\\begin{tikzpicture}\n\\end{tikzpicture}")
+    );
+
+    let mut plot = Plot2D::from_xy(&[1.0, 2.0], &[3.0, 4.0]).unwrap();
+    plot.add_key(PlotKey::Custom(String::from("red")));
+    plot.set_legend("data");
+    let mut axis = Axis::from(plot);
+    axis.add_key(AxisKey::Title(String::from("Title")));
+    let mut picture = Picture::from(axis);
+    picture.add_key(PictureKey::TrimAxisLeft);
+    picture.append_raw("% comment,\n\\node at (0,0) {};");
+
+    let compact = picture.to_string_compact();
+    assert!(!compact.contains('\t'));
+    assert_eq!(
+        compact,
+        String::from(
+            "\\begin{tikzpicture}[trim axis left]
+\\begin{axis}[title={Title}]
+\\addplot[red] coordinates {(1,3) (2,4) };
+\\addlegendentry{data}
+\\end{axis}
+% comment,
+\\node at (0,0) {};
+\\end{tikzpicture}"
+        )
+    );
+    // Same code modulo white space.
+    let strip = |s: &str| s.split_whitespace().collect::<String>().replace(",]", "]");
+    assert_eq!(strip(&compact), strip(&picture.to_string()));
+
+    let plot = Plot2D::from_xy_errors(&[1.0], &[2.0], &[0.1], &[0.2]).unwrap();
+    let compact = Picture::from(plot).to_string_compact();
+    assert!(!compact.contains('\t'));
+    assert!(compact.contains("{(1,2) +- (0.1,0.2) };"));
+}